@@ -0,0 +1,124 @@
+//! An optional in-memory cache for successful `GET` responses.
+//!
+//! The cache is enabled per client via `WattpadClientBuilder::cache` and is only compiled
+//! in with the `cache` feature. Without the feature, `CacheSlot` is an empty struct so the
+//! sub-clients carry no extra state.
+
+#[cfg(feature = "cache")]
+use bytes::Bytes;
+#[cfg(feature = "cache")]
+use std::collections::HashMap;
+#[cfg(feature = "cache")]
+use std::sync::{Arc, Mutex};
+#[cfg(feature = "cache")]
+use std::time::{Duration, Instant};
+
+/// The cache handle shared by a `WattpadClient` and its sub-clients.
+#[derive(Clone, Default)]
+pub(crate) struct CacheSlot(#[cfg(feature = "cache")] pub(crate) Option<Arc<ResponseCache>>);
+
+/// Raw response bodies keyed by request URL, each valid for a fixed time-to-live.
+#[cfg(feature = "cache")]
+pub(crate) struct ResponseCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<String, (Instant, Bytes)>>,
+}
+
+#[cfg(feature = "cache")]
+impl ResponseCache {
+    /// Creates an empty cache whose entries expire after `ttl`.
+    pub(crate) fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Builds the cache key for a request: the full URL followed by its query parameters,
+    /// sorted so that parameter order does not matter.
+    pub(crate) fn key(url: &str, params: &[(&'static str, String)]) -> String {
+        let mut params = params.to_vec();
+        params.sort();
+
+        let query = params
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect::<Vec<_>>()
+            .join("&");
+        format!("{}?{}", url, query)
+    }
+
+    /// Returns the cached body for `key` if it is still within its TTL.
+    pub(crate) fn get(&self, key: &str) -> Option<Bytes> {
+        let mut entries = self.entries.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        match entries.get(key) {
+            Some((stored_at, body)) if stored_at.elapsed() < self.ttl => Some(body.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Stores a response body, pruning any entries that have expired in the meantime.
+    pub(crate) fn insert(&self, key: String, body: Bytes) {
+        let mut entries = self.entries.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        entries.retain(|_, (stored_at, _)| stored_at.elapsed() < self.ttl);
+        entries.insert(key, (Instant::now(), body));
+    }
+
+    /// Drops every entry whose key starts with `prefix`.
+    pub(crate) fn invalidate(&self, prefix: &str) {
+        let mut entries = self.entries.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        entries.retain(|key, _| !key.starts_with(prefix));
+    }
+
+    /// Drops the entries for the given collections of every user, i.e. every key of the form
+    /// `{users_prefix}{name}/{collection}`.
+    pub(crate) fn invalidate_user_collections(&self, users_prefix: &str, collections: &[&str]) {
+        let mut entries = self.entries.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        entries.retain(|key, _| {
+            let Some((_, rest)) = key
+                .strip_prefix(users_prefix)
+                .and_then(|rest| rest.split_once('/'))
+            else {
+                return true;
+            };
+            !collections.iter().any(|collection| {
+                rest.strip_prefix(collection)
+                    .is_some_and(|tail| tail.is_empty() || tail.starts_with(['/', '?']))
+            })
+        });
+    }
+
+    /// Drops every entry.
+    pub(crate) fn clear(&self) {
+        let mut entries = self.entries.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        entries.clear();
+    }
+}
+
+/// Returns the resource a write to `path` affects: its first four segments, e.g.
+/// `/api/v3/lists/123` for `/api/v3/lists/123/stories/456`.
+#[cfg(feature = "cache")]
+pub(crate) fn affected_resource(path: &str) -> &str {
+    match path.match_indices('/').nth(4) {
+        Some((end, _)) => &path[..end],
+        None => path.split('?').next().unwrap_or(path),
+    }
+}
+
+/// Returns the user-scoped collections a write to `path` may change.
+///
+/// Reading list writes change the owner's `lists` and, since the library is itself a reading
+/// list, their `library`. The owner's username is not known here, so these collections are
+/// dropped for every user.
+#[cfg(feature = "cache")]
+pub(crate) fn affected_user_collections(path: &str) -> &'static [&'static str] {
+    if path.starts_with("/api/v3/lists/") || path.starts_with("/api/v3/users/me/lists") {
+        &["lists", "library"]
+    } else {
+        &[]
+    }
+}
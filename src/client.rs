@@ -4,17 +4,32 @@
 //! interactions. It also includes the internal `WattpadRequestBuilder` for constructing
 //! and executing API calls, and helper functions for handling responses.
 
+use crate::cache::CacheSlot;
+#[cfg(feature = "cache")]
+use crate::cache::{affected_resource, affected_user_collections, ResponseCache};
 use crate::error::{ApiErrorResponse, WattpadError};
+use crate::endpoints::comment::CommentClient;
+use crate::endpoints::language::LanguageClient;
+use crate::endpoints::reading_list::ReadingListClient;
+use crate::endpoints::search::SearchClient;
 use crate::endpoints::story::StoryClient;
 use crate::endpoints::user::UserClient;
 use crate::field::{AuthRequiredFields, DefaultableFields};
+use crate::pagination::{PaginatedResponse, PaginationParams};
+use crate::types::{RawApiResponse, SortOrder, StoryFilter};
 use bytes::Bytes;
 use reqwest::Client as ReqwestClient;
 use reqwest::header::{HeaderMap, HeaderName, HeaderValue, USER_AGENT};
 use std::borrow::Cow;
 use std::collections::HashMap;
+#[cfg(feature = "cookie-persistence")]
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+/// The base URL used when none is configured via `WattpadClientBuilder::base_url`.
+const DEFAULT_BASE_URL: &str = "https://www.wattpad.com";
 
 // =================================================================================================
 // WattpadClientBuilder
@@ -26,11 +41,21 @@ pub struct WattpadClientBuilder {
     client: Option<ReqwestClient>,
     user_agent: Option<String>,
     headers: Option<HeaderMap>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    #[cfg(not(target_arch = "wasm32"))]
+    proxies: Vec<reqwest::Proxy>,
+    #[cfg(feature = "cookie-persistence")]
+    cookie_store_path: Option<PathBuf>,
+    base_url: Option<String>,
+    retry: Option<RetryPolicy>,
+    #[cfg(feature = "cache")]
+    cache_ttl: Option<Duration>,
 }
 
 impl WattpadClientBuilder {
     /// Provide a pre-configured `reqwest::Client`.
-    /// If this is used, any other configurations like `.user_agent()` or `.header()` will be ignored,
+    /// If this is used, any other configurations like `.user_agent()`, `.header()` or `.timeout()` will be ignored,
     /// as the provided client is assumed to be fully configured.
     pub fn reqwest_client(mut self, client: ReqwestClient) -> Self {
         self.client = Some(client);
@@ -49,10 +74,120 @@ impl WattpadClientBuilder {
         self
     }
 
+    /// Set the total timeout for each request, from connecting until the response body
+    /// has been fully received.
+    ///
+    /// By default, requests never time out. Note that large downloads (e.g.,
+    /// `get_story_content_zip`) may need a generous value.
+    pub fn timeout(mut self, duration: Duration) -> Self {
+        self.timeout = Some(duration);
+        self
+    }
+
+    /// Set a timeout for only the connection phase of each request.
+    ///
+    /// By default, connecting never times out. A short connect timeout allows failing
+    /// fast on unreachable hosts without limiting how long a slow download may take.
+    pub fn connect_timeout(mut self, duration: Duration) -> Self {
+        self.connect_timeout = Some(duration);
+        self
+    }
+
+    /// Route requests through a proxy.
+    ///
+    /// Can be called multiple times; proxies are added to the underlying client in the
+    /// order they were given. Like the other settings, this is silently ignored when a
+    /// custom client is supplied via `.reqwest_client()`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Persist session cookies to a file so that authentication survives process restarts.
+    ///
+    /// On `.build()`, cookies are loaded from `path` if it exists. If the file cannot be read or
+    /// is corrupt, the client starts with an empty cookie jar and a warning is logged. If the
+    /// loaded cookies contain a Wattpad session, the client starts out authenticated.
+    ///
+    /// Cookies are written back when the last clone of the client is dropped, or explicitly via
+    /// `WattpadClient::save_session()`. Ignored when a custom client is supplied via
+    /// `.reqwest_client()`.
+    #[cfg(feature = "cookie-persistence")]
+    pub fn cookie_store_path(mut self, path: impl AsRef<Path>) -> Self {
+        self.cookie_store_path = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Override the base URL that all requests are sent to, instead of `https://www.wattpad.com`.
+    ///
+    /// This is mainly useful for pointing the client at a local mock server in tests. A trailing
+    /// slash is ignored, so `"http://localhost:8080"` and `"http://localhost:8080/"` are equivalent.
+    pub fn base_url(mut self, url: &str) -> Self {
+        self.base_url = Some(url.trim_end_matches('/').to_string());
+        self
+    }
+
+    /// Automatically retry requests that fail with a network error or an HTTP 429, 502, 503 or
+    /// 504 response.
+    ///
+    /// `max_attempts` is the total number of attempts, including the first. The delay starts at
+    /// `base_delay` and doubles after every attempt, with ±25% random jitter. A `Retry-After`
+    /// header on a 429 response takes precedence over the computed delay. Once all attempts are
+    /// used up, the last error is returned.
+    ///
+    /// Only safe methods such as `GET` are retried unless `.retry_safe_methods_only(false)` is
+    /// also set. Retries are not available on `wasm32` targets.
+    pub fn retry(mut self, max_attempts: u32, base_delay: Duration) -> Self {
+        let safe_methods_only = self.retry.is_none_or(|policy| policy.safe_methods_only);
+        self.retry = Some(RetryPolicy {
+            max_attempts: max_attempts.max(1),
+            base_delay,
+            safe_methods_only,
+        });
+        self
+    }
+
+    /// Whether retries configured with `.retry()` are limited to safe methods (the default).
+    ///
+    /// Setting this to `false` also retries write operations such as voting or adding a story
+    /// to a reading list, which may then be applied more than once.
+    pub fn retry_safe_methods_only(mut self, safe_methods_only: bool) -> Self {
+        if let Some(policy) = &mut self.retry {
+            policy.safe_methods_only = safe_methods_only;
+        } else {
+            self.retry = Some(RetryPolicy {
+                max_attempts: 1,
+                base_delay: Duration::ZERO,
+                safe_methods_only,
+            });
+        }
+        self
+    }
+
+    /// Cache successful `GET` responses in memory for `ttl`.
+    ///
+    /// Repeated requests for the same URL and query parameters within the TTL are answered
+    /// from the cache without a network call. Write operations such as voting or adding a story
+    /// to a reading list evict the cached entries of the resource they modify, and logging in or
+    /// out clears the cache entirely. Reading list writes also evict every cached user's lists
+    /// and library.
+    #[cfg(feature = "cache")]
+    pub fn cache(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = Some(ttl);
+        self
+    }
+
     /// Builds the `WattpadClient`.
     ///
     /// If a `reqwest::Client` was not provided via the builder, a new default one will be created.
     pub fn build(self) -> WattpadClient {
+        #[cfg(feature = "cookie-persistence")]
+        let session = match (&self.client, self.cookie_store_path) {
+            (None, Some(path)) => Some(Arc::new(SessionStore::load(path))),
+            _ => None,
+        };
+
         let http_client = match self.client {
             // If a client was provided, use it directly.
             Some(client) => client,
@@ -74,6 +209,21 @@ impl WattpadClientBuilder {
                 #[cfg(not(target_arch = "wasm32"))]
                 {
                     client_builder = client_builder.cookie_store(true);
+
+                    if let Some(timeout) = self.timeout {
+                        client_builder = client_builder.timeout(timeout);
+                    }
+                    if let Some(connect_timeout) = self.connect_timeout {
+                        client_builder = client_builder.connect_timeout(connect_timeout);
+                    }
+                    for proxy in self.proxies {
+                        client_builder = client_builder.proxy(proxy);
+                    }
+
+                    #[cfg(feature = "cookie-persistence")]
+                    if let Some(session) = &session {
+                        client_builder = client_builder.cookie_provider(session.jar.clone());
+                    }
                 }
  
                 client_builder.build()
@@ -82,18 +232,203 @@ impl WattpadClientBuilder {
         };
 
         // The rest of the logic remains the same
+        #[cfg(not(feature = "cookie-persistence"))]
         let auth_flag = Arc::new(AtomicBool::new(false));
+        #[cfg(feature = "cookie-persistence")]
+        let auth_flag = Arc::new(AtomicBool::new(
+            session.as_ref().is_some_and(|session| session.has_session()),
+        ));
+        let base_url = Arc::new(self.base_url.unwrap_or_else(|| DEFAULT_BASE_URL.to_string()));
+        #[cfg(feature = "cache")]
+        let cache = CacheSlot(self.cache_ttl.map(|ttl| Arc::new(ResponseCache::new(ttl))));
+        #[cfg(not(feature = "cache"))]
+        let cache = CacheSlot::default();
         WattpadClient {
             user: UserClient {
                 http: http_client.clone(),
                 is_authenticated: auth_flag.clone(),
+                base_url: base_url.clone(),
+                timeout: None,
+                retry: self.retry,
+                cache: cache.clone(),
             },
             story: StoryClient {
                 http: http_client.clone(),
                 is_authenticated: auth_flag.clone(),
+                base_url: base_url.clone(),
+                timeout: None,
+                retry: self.retry,
+                cache: cache.clone(),
+            },
+            search: SearchClient {
+                http: http_client.clone(),
+                is_authenticated: auth_flag.clone(),
+                base_url: base_url.clone(),
+                timeout: None,
+                retry: self.retry,
+                cache: cache.clone(),
+            },
+            reading_list: ReadingListClient {
+                http: http_client.clone(),
+                is_authenticated: auth_flag.clone(),
+                base_url: base_url.clone(),
+                timeout: None,
+                retry: self.retry,
+                cache: cache.clone(),
+            },
+            comment: CommentClient {
+                http: http_client.clone(),
+                is_authenticated: auth_flag.clone(),
+                base_url: base_url.clone(),
+                timeout: None,
+                retry: self.retry,
+                cache: cache.clone(),
+            },
+            language: LanguageClient {
+                http: http_client.clone(),
+                is_authenticated: auth_flag.clone(),
+                base_url: base_url.clone(),
+                timeout: None,
+                retry: self.retry,
+                cache: cache.clone(),
             },
             http: http_client,
             is_authenticated: auth_flag,
+            base_url,
+            cache,
+            #[cfg(feature = "cookie-persistence")]
+            session,
+        }
+    }
+}
+
+// =================================================================================================
+// Retry Policy
+// =================================================================================================
+
+/// How failed requests are retried, as configured via `WattpadClientBuilder::retry`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RetryPolicy {
+    /// The total number of attempts, including the first one.
+    max_attempts: u32,
+    /// The delay before the first retry; doubled after every attempt.
+    base_delay: Duration,
+    /// Whether only safe methods (e.g., `GET`) may be retried.
+    safe_methods_only: bool,
+}
+
+impl RetryPolicy {
+    /// Computes the jittered exponential backoff to wait after the given (1-based) attempt.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let exponential = self
+            .base_delay
+            .saturating_mul(1 << attempt.saturating_sub(1).min(16));
+        exponential.mul_f64(jitter_factor())
+    }
+}
+
+/// Returns a pseudo-random factor in `[0.75, 1.25)` used to spread out retries.
+///
+/// `RandomState` is seeded randomly per instance, which is plenty for jitter and avoids
+/// pulling in a dedicated RNG crate.
+fn jitter_factor() -> f64 {
+    use std::hash::BuildHasher;
+
+    let random = std::collections::hash_map::RandomState::new().hash_one(0u8);
+    0.75 + (random % 1000) as f64 / 2000.0
+}
+
+/// Whether a response status is worth retrying: rate limiting or a transient gateway error.
+fn is_retryable_status(status: reqwest::StatusCode) -> bool {
+    matches!(status.as_u16(), 429 | 502 | 503 | 504)
+}
+
+/// Parses a `Retry-After` header, given either as a number of seconds or as an HTTP date.
+///
+/// A date in the past yields a zero delay.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(reqwest::header::RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(
+        date.duration_since(std::time::SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+/// Waits before the next retry attempt.
+async fn sleep(duration: Duration) {
+    #[cfg(not(target_arch = "wasm32"))]
+    tokio::time::sleep(duration).await;
+    #[cfg(target_arch = "wasm32")]
+    let _ = duration;
+}
+
+// =================================================================================================
+// Cookie Persistence
+// =================================================================================================
+
+/// A file-backed cookie jar, flushed to disk when the last reference to it is dropped.
+#[cfg(feature = "cookie-persistence")]
+struct SessionStore {
+    jar: Arc<reqwest_cookie_store::CookieStoreMutex>,
+    path: PathBuf,
+}
+
+#[cfg(feature = "cookie-persistence")]
+impl SessionStore {
+    /// Loads the cookie jar from `path`, falling back to an empty jar if it is missing or unreadable.
+    fn load(path: PathBuf) -> Self {
+        let store = match std::fs::File::open(&path) {
+            Ok(file) => cookie_store::serde::json::load(std::io::BufReader::new(file))
+                .unwrap_or_else(|e| {
+                    log::warn!("Ignoring corrupt cookie store at '{}': {}", path.display(), e);
+                    reqwest_cookie_store::CookieStore::default()
+                }),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                reqwest_cookie_store::CookieStore::default()
+            }
+            Err(e) => {
+                log::warn!("Could not open cookie store at '{}': {}", path.display(), e);
+                reqwest_cookie_store::CookieStore::default()
+            }
+        };
+
+        SessionStore {
+            jar: Arc::new(reqwest_cookie_store::CookieStoreMutex::new(store)),
+            path,
+        }
+    }
+
+    /// Whether the jar holds a Wattpad session token from a previous login.
+    fn has_session(&self) -> bool {
+        let url = reqwest::Url::parse("https://www.wattpad.com/").expect("Invalid Wattpad URL");
+        let store = self.jar.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        store.matches(&url).iter().any(|cookie| cookie.name() == "token")
+    }
+
+    /// Writes all persistent, unexpired cookies to the backing file.
+    fn save(&self) -> Result<(), WattpadError> {
+        let to_error = |reason: String| WattpadError::SessionPersistence {
+            path: self.path.display().to_string(),
+            reason,
+        };
+
+        let file = std::fs::File::create(&self.path).map_err(|e| to_error(e.to_string()))?;
+        let mut writer = std::io::BufWriter::new(file);
+        let store = self.jar.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        cookie_store::serde::json::save(&store, &mut writer).map_err(|e| to_error(e.to_string()))
+    }
+}
+
+#[cfg(feature = "cookie-persistence")]
+impl Drop for SessionStore {
+    fn drop(&mut self) {
+        if let Err(e) = self.save() {
+            log::warn!("{}", e);
         }
     }
 }
@@ -102,15 +437,36 @@ impl WattpadClientBuilder {
 ///
 /// This client holds the HTTP connection, manages authentication state, and provides
 /// access to categorized sub-clients for different parts of the API.
+///
+/// Cloning is cheap, and clones share the same connection pool, cookie jar and
+/// authentication state, so a client can be authenticated once and then cloned
+/// into as many tasks as needed.
+#[derive(Clone)]
 pub struct WattpadClient {
     /// The underlying `reqwest` client used for all HTTP requests.
-    http: reqwest::Client,
+    pub(crate) http: reqwest::Client,
     /// An atomically-managed boolean flag to track authentication status.
     is_authenticated: Arc<AtomicBool>,
+    /// The base URL all requests are sent to.
+    base_url: Arc<String>,
+    /// The response cache shared with the sub-clients, if enabled.
+    #[cfg_attr(not(feature = "cache"), allow(dead_code))]
+    cache: CacheSlot,
     /// Provides access to user-related API endpoints.
     pub user: UserClient,
     /// Provides access to story and part-related API endpoints.
     pub story: StoryClient,
+    /// Provides access to search-related API endpoints.
+    pub search: SearchClient,
+    /// Provides access to reading list-related API endpoints.
+    pub reading_list: ReadingListClient,
+    /// Provides access to comment-related API endpoints.
+    pub comment: CommentClient,
+    /// Provides access to language-related API endpoints.
+    pub language: LanguageClient,
+    /// The file-backed cookie jar, if `cookie_store_path` was configured.
+    #[cfg(feature = "cookie-persistence")]
+    session: Option<Arc<SessionStore>>,
 }
 
 impl WattpadClient {
@@ -143,13 +499,24 @@ impl WattpadClient {
     /// # Errors
     /// Returns `WattpadError::AuthenticationFailed` if login is unsuccessful.
     pub async fn authenticate(&self, username: &str, password: &str) -> Result<(), WattpadError> {
-        let url = "https://www.wattpad.com/auth/login?&_data=routes%2Fauth.login";
+        let login = self.login(username, password);
+        #[cfg(feature = "tracing")]
+        let login = tracing::Instrument::instrument(
+            login,
+            tracing::info_span!("wattpad_authenticate", username = %username),
+        );
+        login.await
+    }
+
+    /// Performs the login flow for `authenticate`.
+    async fn login(&self, username: &str, password: &str) -> Result<(), WattpadError> {
+        let url = format!("{}/auth/login?&_data=routes%2Fauth.login", self.base_url);
 
         let mut payload = HashMap::new();
         payload.insert("username", username);
         payload.insert("password", password);
 
-        let response = self.http.post(url).form(&payload).send().await?;
+        let response = self.http.post(&url).form(&payload).send().await?;
 
         // --- NATIVE-SPECIFIC LOGIC ---
         // For native builds, we verify that cookies were actually returned.
@@ -171,6 +538,11 @@ impl WattpadClient {
             }
         }
 
+        #[cfg(feature = "cache")]
+        if let Some(cache) = &self.cache.0 {
+            cache.clear();
+        }
+
         self.is_authenticated.store(true, Ordering::SeqCst);
         Ok(())
     }
@@ -186,17 +558,57 @@ impl WattpadClient {
     /// # Errors
     /// Returns a `WattpadError` if the HTTP request fails.
     pub async fn deauthenticate(&self) -> Result<(), WattpadError> {
-        let url = "https://www.wattpad.com/logout";
+        let url = format!("{}/logout", self.base_url);
 
         // 1. Send a GET request to the logout URL. The reqwest client's cookie store
         //    will automatically handle the updated (cleared) session cookies from the response.
-        self.http.get(url).send().await?;
+        self.http.get(&url).send().await?;
 
-        // 2. Set the local authentication flag to false.
+        // 2. Set the local authentication flag to false, and forget any responses that
+        //    may have been fetched with the old session.
         self.is_authenticated.store(false, Ordering::SeqCst);
+        #[cfg(feature = "cache")]
+        if let Some(cache) = &self.cache.0 {
+            cache.clear();
+        }
         Ok(())
     }
 
+    /// Clears the client's local authentication state without contacting Wattpad.
+    ///
+    /// Unlike `deauthenticate`, no logout request is sent. This is useful when the session has
+    /// already expired or been invalidated elsewhere, before calling `authenticate` again. With
+    /// the `cookie-persistence` feature, the cookies held in the persisted jar are also cleared;
+    /// cookies in the default in-memory jar are replaced on the next login.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use wp_mini::{WattpadClient, WattpadError};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), WattpadError> {
+    /// let client = WattpadClient::new();
+    /// client.authenticate("username", "password").await?;
+    ///
+    /// // Later, after the session expired elsewhere:
+    /// client.reset_authentication();
+    /// client.authenticate("username", "password").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reset_authentication(&self) {
+        self.is_authenticated.store(false, Ordering::SeqCst);
+
+        #[cfg(feature = "cookie-persistence")]
+        if let Some(session) = &self.session {
+            session.jar.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).clear();
+        }
+        #[cfg(feature = "cache")]
+        if let Some(cache) = &self.cache.0 {
+            cache.clear();
+        }
+    }
+
     /// Checks if the client has been successfully authenticated.
     ///
     /// # Returns
@@ -204,6 +616,39 @@ impl WattpadClient {
     pub fn is_authenticated(&self) -> bool {
         self.is_authenticated.load(Ordering::SeqCst)
     }
+
+    /// Writes the current session cookies to the file configured with `cookie_store_path`.
+    ///
+    /// Cookies are also saved automatically when the client is dropped; call this to persist
+    /// them earlier, e.g. right after `authenticate`. Does nothing if no path was configured.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::SessionPersistence` if the file cannot be written.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use wp_mini::{WattpadClient, WattpadError};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), WattpadError> {
+    /// let client = WattpadClient::builder()
+    ///     .cookie_store_path("wattpad-session.json")
+    ///     .build();
+    ///
+    /// if !client.is_authenticated() {
+    ///     client.authenticate("username", "password").await?;
+    ///     client.save_session()?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "cookie-persistence")]
+    pub fn save_session(&self) -> Result<(), WattpadError> {
+        match &self.session {
+            Some(session) => session.save(),
+            None => Ok(()),
+        }
+    }
 }
 
 /// Provides a default implementation for `WattpadClient`.
@@ -221,14 +666,156 @@ impl Default for WattpadClient {
 /// Otherwise, it attempts to parse a specific `ApiErrorResponse` format from the body.
 async fn handle_response<T: serde::de::DeserializeOwned>(
     response: reqwest::Response,
+    endpoint: &str,
 ) -> Result<T, WattpadError> {
-    if response.status().is_success() {
-        let json = response.json::<T>().await?;
-        Ok(json)
+    let result = if response.status().is_success() {
+        let body = response.bytes().await?;
+        parse_json(&body, endpoint)
     } else {
-        let error_response = response.json::<ApiErrorResponse>().await?;
-        Err(error_response.into())
+        Err(error_from_response(response).await)
+    };
+
+    #[cfg(feature = "log")]
+    if let Err(e) = &result {
+        log::error!("Request to {} failed: {}", endpoint, e);
     }
+    result
+}
+
+/// A private helper that deserializes a JSON body, recording the endpoint and target type
+/// in the error if it does not match.
+fn parse_json<T: serde::de::DeserializeOwned>(body: &[u8], endpoint: &str) -> Result<T, WattpadError> {
+    serde_json::from_slice(body).map_err(|source| {
+        let error = WattpadError::ParseError {
+            source,
+            endpoint: endpoint.to_string(),
+            type_name: std::any::type_name::<T>(),
+        };
+        #[cfg(feature = "metrics")]
+        record_error(&error);
+        error
+    })
+}
+
+/// Increments `wattpad.errors.total`, labelled with the error variant.
+#[cfg(feature = "metrics")]
+fn record_error(error: &WattpadError) {
+    metrics::counter!("wattpad.errors.total", "error_type" => error.kind()).increment(1);
+}
+
+/// Replaces the numeric segments of an API path with `{id}`, so that metrics are labelled
+/// per endpoint rather than per resource.
+#[cfg(feature = "metrics")]
+fn metric_path(path: &str) -> String {
+    path.split('/')
+        .map(|segment| {
+            if !segment.is_empty() && segment.bytes().all(|b| b.is_ascii_digit()) {
+                "{id}"
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// A private helper that converts an unsuccessful response into a `WattpadError`.
+///
+/// HTTP 429 responses become `WattpadError::RateLimited`. Otherwise the body is parsed as
+/// a Wattpad API error, falling back to `WattpadError::HttpError` if it is not one.
+async fn error_from_response(response: reqwest::Response) -> WattpadError {
+    let status = response.status();
+    let error = if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        WattpadError::RateLimited {
+            retry_after: retry_after(response.headers()),
+        }
+    } else {
+        match response.text().await {
+            Ok(body) => match serde_json::from_str::<ApiErrorResponse>(&body) {
+                Ok(error_response) => error_response.into(),
+                Err(_) => WattpadError::HttpError {
+                    status: status.as_u16(),
+                    body,
+                },
+            },
+            Err(e) => e.into(),
+        }
+    };
+
+    #[cfg(feature = "metrics")]
+    record_error(&error);
+    error
+}
+
+/// A helper that sends a plain `GET` for a URL outside the API, such as an image on
+/// Wattpad's CDN, and returns the response if its status is successful.
+pub(crate) async fn download(
+    http: &reqwest::Client,
+    url: &str,
+) -> Result<reqwest::Response, WattpadError> {
+    let response = http.get(url).send().await?;
+    let status = response.status();
+    if status.is_success() {
+        Ok(response)
+    } else {
+        Err(WattpadError::HttpError {
+            status: status.as_u16(),
+            body: response.text().await?,
+        })
+    }
+}
+
+/// A helper that wraps an image upload in a multipart form under the `image` field.
+///
+/// Only JPEG, PNG and GIF images are accepted, matching what Wattpad allows for avatars and
+/// covers; anything else is rejected as `WattpadError::InvalidInput` before any request.
+pub(crate) fn image_form(
+    image_bytes: Bytes,
+    content_type: &str,
+    name: &str,
+) -> Result<reqwest::multipart::Form, WattpadError> {
+    let extension = match content_type {
+        "image/jpeg" => "jpg",
+        "image/png" => "png",
+        "image/gif" => "gif",
+        _ => {
+            return Err(WattpadError::InvalidInput {
+                field: "content_type".to_string(),
+                reason: format!(
+                    "Expected image/jpeg, image/png or image/gif, got '{}'.",
+                    content_type
+                ),
+            });
+        }
+    };
+
+    let part = reqwest::multipart::Part::bytes(image_bytes.to_vec())
+        .file_name(format!("{}.{}", name, extension))
+        .mime_str(content_type)?;
+    Ok(reqwest::multipart::Form::new().part("image", part))
+}
+
+/// The envelope object that Wattpad wraps list endpoint results in.
+///
+/// The items themselves live under an endpoint-specific key (e.g., `stories`), so they are
+/// captured generically and extracted by `WattpadRequestBuilder::execute_paginated`.
+#[derive(serde::Deserialize)]
+struct ListEnvelope {
+    total: Option<u64>,
+    #[serde(rename = "nextUrl")]
+    next_url: Option<String>,
+    #[serde(flatten)]
+    collections: serde_json::Map<String, serde_json::Value>,
+}
+
+/// A private helper to extract the `offset` query parameter from a `nextUrl` value.
+fn offset_from_url(url: &str) -> Option<u32> {
+    let url = reqwest::Url::parse(url)
+        .or_else(|_| reqwest::Url::parse("https://www.wattpad.com")?.join(url))
+        .ok()?;
+    url.query_pairs()
+        .find(|(key, _)| key == "offset")
+        .and_then(|(_, value)| value.parse().ok())
 }
 
 // =================================================================================================
@@ -240,10 +827,21 @@ async fn handle_response<T: serde::de::DeserializeOwned>(
 pub(crate) struct WattpadRequestBuilder<'a> {
     client: &'a reqwest::Client,
     is_authenticated: &'a Arc<AtomicBool>,
+    base_url: &'a str,
     method: reqwest::Method,
     path: String,
+    timeout: Option<Duration>,
+    retry: Option<RetryPolicy>,
+    #[cfg(feature = "cache")]
+    cache: Option<&'a ResponseCache>,
     params: Vec<(&'static str, String)>,
+    json_body: Option<Result<serde_json::Value, String>>,
+    /// A form can only be sent once, so the first attempt takes it out of the slot.
+    multipart: Mutex<Option<reqwest::multipart::Form>>,
     auth_required: bool,
+    collection: Option<&'static str>,
+    limit: Option<u32>,
+    offset: Option<u32>,
 }
 
 impl<'a> WattpadRequestBuilder<'a> {
@@ -251,16 +849,27 @@ impl<'a> WattpadRequestBuilder<'a> {
     pub(crate) fn new(
         client: &'a reqwest::Client,
         is_authenticated: &'a Arc<AtomicBool>,
+        base_url: &'a str,
         method: reqwest::Method,
         path: &str,
     ) -> Self {
         Self {
             client,
             is_authenticated,
+            base_url,
             method,
             path: path.to_string(),
+            timeout: None,
+            retry: None,
+            #[cfg(feature = "cache")]
+            cache: None,
             params: Vec::new(),
+            json_body: None,
+            multipart: Mutex::new(None),
             auth_required: false,
+            collection: None,
+            limit: None,
+            offset: None,
         }
     }
 
@@ -293,6 +902,16 @@ impl<'a> WattpadRequestBuilder<'a> {
         self
     }
 
+    /// Marks the request as targeting a list endpoint whose results are nested under `key`.
+    ///
+    /// List endpoints wrap their results in an envelope object (e.g., `{"comments": [...]}`),
+    /// so requested fields are nested under the same key (e.g., `comments(id,text)`).
+    /// This must be called before `fields` for the nesting to take effect.
+    pub(crate) fn collection(mut self, key: &'static str) -> Self {
+        self.collection = Some(key);
+        self
+    }
+
     /// Adds the `fields` query parameter for field selection.
     ///
     /// This method handles using default fields if none are provided. It also performs a
@@ -311,16 +930,16 @@ impl<'a> WattpadRequestBuilder<'a> {
             _ => Cow::from(T::default_fields()),
         };
 
-        if !self.is_authenticated.load(Ordering::SeqCst) {
-            if let Some(auth_field) = fields_to_query.iter().find(|f| f.auth_required()) {
-                return Err(WattpadError::AuthenticationRequired {
-                    field: auth_field.to_string(),
-                    context: format!(
-                        "The field '{}' requires authentication.",
-                        auth_field.to_string()
-                    ),
-                });
-            }
+        if !self.is_authenticated.load(Ordering::SeqCst)
+            && let Some(auth_field) = fields_to_query.iter().find(|f| f.auth_required())
+        {
+            return Err(WattpadError::AuthenticationRequired {
+                field: auth_field.to_string(),
+                context: format!(
+                    "The field '{}' requires authentication.",
+                    auth_field.to_string()
+                ),
+            });
         }
 
         let fields_str = fields_to_query
@@ -329,10 +948,48 @@ impl<'a> WattpadRequestBuilder<'a> {
             .collect::<Vec<_>>()
             .join(",");
 
+        // List endpoints nest the requested fields under their collection key, alongside
+        // the envelope fields needed to build a `PaginatedResponse`.
+        let fields_str = match self.collection {
+            Some(key) => format!("{}({}),total,nextUrl", key, fields_str),
+            None => fields_str,
+        };
+
         self.params.push(("fields", fields_str));
         Ok(self)
     }
 
+    /// Adds the `limit` and `offset` query parameters for a paginated list endpoint.
+    ///
+    /// The values are also remembered so they can be reported back in the `PaginatedResponse`.
+    pub(crate) fn pagination(mut self, pagination: Option<PaginationParams>) -> Self {
+        let PaginationParams { limit, offset } = pagination.unwrap_or_default();
+        self.limit = limit;
+        self.offset = offset;
+        self.maybe_param("limit", limit).maybe_param("offset", offset)
+    }
+
+    /// Adds a query parameter for every field of `filter` that is set.
+    pub(crate) fn story_filter(self, filter: &StoryFilter) -> Self {
+        self.maybe_param("language", filter.language)
+            .maybe_param("mature", filter.mature)
+            .maybe_param("completed", filter.completed)
+            .maybe_param("category", filter.category)
+            .maybe_param("rating", filter.rating)
+    }
+
+    /// Adds the `sort` and `order` query parameters, or nothing to keep the API's default order.
+    ///
+    /// The field is given by its API name, e.g. `StorySortField::code`.
+    pub(crate) fn sort(self, sort_by: Option<(&'static str, SortOrder)>) -> Self {
+        match sort_by {
+            Some((field, order)) => self
+                .param("sort", Some(field))
+                .param("order", Some(order.code())),
+            None => self,
+        }
+    }
+
     /// Adds a query parameter to the request.
     pub(crate) fn param<T: ToString>(mut self, key: &'static str, value: Option<T>) -> Self {
         if let Some(val) = value {
@@ -341,37 +998,346 @@ impl<'a> WattpadRequestBuilder<'a> {
         self
     }
 
-    /// Executes the request and deserializes the JSON response into a specified type `T`.
-    pub(crate) async fn execute<T: serde::de::DeserializeOwned>(self) -> Result<T, WattpadError> {
+    /// Sets a JSON request body, sent alongside any query parameters.
+    ///
+    /// A body that cannot be serialized is reported as `WattpadError::InvalidInput` when the
+    /// request is sent. Mutually exclusive with `multipart`; setting both panics in debug
+    /// builds.
+    pub(crate) fn json_body<S: serde::Serialize>(mut self, body: &S) -> Self {
+        debug_assert!(
+            self.multipart_form().is_none(),
+            "json_body and multipart are mutually exclusive"
+        );
+        self.json_body = Some(serde_json::to_value(body).map_err(|e| e.to_string()));
+        self
+    }
+
+    /// Sets a `multipart/form-data` request body, for file uploads.
+    ///
+    /// A form can only be sent once, so requests with a multipart body are never retried.
+    /// Mutually exclusive with `json_body`; setting both panics in debug builds.
+    pub(crate) fn multipart(self, form: reqwest::multipart::Form) -> Self {
+        debug_assert!(
+            self.json_body.is_none(),
+            "json_body and multipart are mutually exclusive"
+        );
+        *self.multipart_form() = Some(form);
+        self
+    }
+
+    /// Locks the multipart slot. The lock is never held across an await, so poisoning can
+    /// only follow a panic elsewhere and the form inside is still usable.
+    fn multipart_form(&self) -> std::sync::MutexGuard<'_, Option<reqwest::multipart::Form>> {
+        self.multipart.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Sets a timeout for this request, if one is given.
+    ///
+    /// The timeout covers sending the request and receiving the response headers. It is
+    /// applied on top of any client-wide timeout, so the shorter of the two wins.
+    pub(crate) fn timeout(mut self, duration: Option<Duration>) -> Self {
+        if let Some(duration) = duration {
+            self.timeout = Some(duration);
+        }
+        self
+    }
+
+    /// Enables retrying this request according to the given policy, if one is given.
+    pub(crate) fn retry(mut self, policy: Option<RetryPolicy>) -> Self {
+        if policy.is_some() {
+            self.retry = policy;
+        }
+        self
+    }
+
+    /// Attaches the client's response cache to this request.
+    ///
+    /// Successful `GET` responses are then served from and stored in the cache, while any
+    /// other method evicts the cached entries of the resource it modifies.
+    #[cfg_attr(not(feature = "cache"), allow(unused_mut, unused_variables))]
+    pub(crate) fn cache(mut self, cache: &'a CacheSlot) -> Self {
+        #[cfg(feature = "cache")]
+        {
+            self.cache = cache.0.as_deref();
+        }
+        self
+    }
+
+    /// A private helper that sends the request, recording it in a `wattpad_request` span
+    /// when the `tracing` feature is enabled.
+    async fn send(&self) -> Result<reqwest::Response, WattpadError> {
+        #[cfg(feature = "tracing")]
+        let span = tracing::info_span!(
+            "wattpad_request",
+            method = %self.method,
+            path = %self.path,
+            status = tracing::field::Empty,
+            elapsed_ms = tracing::field::Empty,
+        );
+        // `Instant` is not available on `wasm32-unknown-unknown`.
+        #[cfg(all(any(feature = "tracing", feature = "metrics"), not(target_arch = "wasm32")))]
+        let start = std::time::Instant::now();
+
+        #[cfg(feature = "log")]
+        log::debug!("Sending {} {}", self.method, self.path);
+
+        let result = self.send_with_retry();
+        #[cfg(feature = "tracing")]
+        let result = tracing::Instrument::instrument(result, span.clone());
+        let result = result.await;
+
+        #[cfg(feature = "tracing")]
+        {
+            if let Ok(response) = &result {
+                span.record("status", response.status().as_u16());
+            }
+            #[cfg(not(target_arch = "wasm32"))]
+            span.record("elapsed_ms", start.elapsed().as_millis() as u64);
+        }
+
+        #[cfg(feature = "metrics")]
+        {
+            let method = self.method.to_string();
+            let path = metric_path(&self.path);
+            #[cfg(not(target_arch = "wasm32"))]
+            metrics::histogram!(
+                "wattpad.request.duration.seconds",
+                "method" => method.clone(),
+                "path" => path.clone(),
+            )
+                .record(start.elapsed().as_secs_f64());
+            metrics::counter!("wattpad.requests.total", "method" => method, "path" => path)
+                .increment(1);
+            if let Err(e) = &result {
+                record_error(e);
+            }
+        }
+
+        #[cfg(feature = "log")]
+        if let Ok(response) = &result
+            && !response.status().is_success()
+        {
+            log::warn!("{} {} returned {}", self.method, self.path, response.status());
+        }
+
+        result
+    }
+
+    /// Checks endpoint authentication, then sends the request, retrying transient failures
+    /// if a retry policy is set.
+    async fn send_with_retry(&self) -> Result<reqwest::Response, WattpadError> {
         self.check_endpoint_auth()?;
 
-        let url = format!("https://www.wattpad.com{}", self.path);
-        let response = self
+        let url = format!("{}{}", self.base_url, self.path);
+
+        #[cfg(feature = "cache")]
+        if let Some(cache) = self.cache
+            && self.method != reqwest::Method::GET
+        {
+            cache.invalidate(&format!("{}{}", self.base_url, affected_resource(&self.path)));
+            cache.invalidate_user_collections(
+                &format!("{}/api/v3/users/", self.base_url),
+                affected_user_collections(&self.path),
+            );
+        }
+
+        // Backoff needs a timer, which is only available on native targets.
+        #[cfg(target_arch = "wasm32")]
+        let retry: Option<RetryPolicy> = None;
+        #[cfg(not(target_arch = "wasm32"))]
+        let retry = self
+            .retry
+            .filter(|policy| !policy.safe_methods_only || self.method.is_safe())
+            .filter(|_| self.multipart_form().is_none());
+
+        let mut attempt = 1;
+        loop {
+            let result = self.send_once(&url).await;
+
+            let Some(policy) = retry.filter(|policy| attempt < policy.max_attempts) else {
+                return result;
+            };
+            let delay = match &result {
+                Ok(response) if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS => {
+                    retry_after(response.headers()).unwrap_or_else(|| policy.backoff(attempt))
+                }
+                Ok(response) if is_retryable_status(response.status()) => policy.backoff(attempt),
+                Err(WattpadError::RequestError(_) | WattpadError::Timeout) => {
+                    policy.backoff(attempt)
+                }
+                _ => return result,
+            };
+
+            sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// Builds and sends a single attempt of the request, applying the per-request timeout.
+    async fn send_once(&self, url: &str) -> Result<reqwest::Response, WattpadError> {
+        let mut request = self
             .client
-            .request(self.method, &url)
-            .query(&self.params)
-            .send()
-            .await?;
-        handle_response(response).await
+            .request(self.method.clone(), url)
+            .query(&self.params);
+        if let Some(body) = &self.json_body {
+            let body = body.as_ref().map_err(|reason| WattpadError::InvalidInput {
+                field: "body".to_string(),
+                reason: format!("The request body could not be serialized: {}", reason),
+            })?;
+            request = request.json(body);
+        }
+        if let Some(form) = self.multipart_form().take() {
+            request = request.multipart(form);
+        }
+        let request = request.send();
+
+        // Per-request timeouts need a timer, which is only available on native targets.
+        #[cfg(not(target_arch = "wasm32"))]
+        let response = match self.timeout {
+            Some(duration) => tokio::time::timeout(duration, request)
+                .await
+                .map_err(|_| WattpadError::Timeout)??,
+            None => request.await?,
+        };
+        #[cfg(target_arch = "wasm32")]
+        let response = request.await?;
+
+        Ok(response)
+    }
+
+    /// Executes the request and deserializes the JSON response into a specified type `T`.
+    pub(crate) async fn execute<T: serde::de::DeserializeOwned>(self) -> Result<T, WattpadError> {
+        #[cfg(feature = "cache")]
+        if let Some(cache) = self.cache
+            && self.method == reqwest::Method::GET
+        {
+            let key = ResponseCache::key(&format!("{}{}", self.base_url, self.path), &self.params);
+            if let Some(body) = cache.get(&key) {
+                return parse_json(&body, &self.path);
+            }
+
+            let response = self.send().await?;
+            if !response.status().is_success() {
+                return handle_response(response, &self.path).await;
+            }
+            let body = response.bytes().await?;
+            let value = parse_json(&body, &self.path)?;
+            cache.insert(key, body);
+            return Ok(value);
+        }
+
+        let response = self.send().await?;
+        handle_response(response, &self.path).await
+    }
+
+    /// Executes the request like `execute`, keeping the raw JSON body alongside the value
+    /// deserialized from it.
+    pub(crate) async fn execute_with_raw<T: serde::de::DeserializeOwned>(
+        self,
+    ) -> Result<RawApiResponse<T>, WattpadError> {
+        let path = self.path.clone();
+        let raw: serde_json::Value = self.execute().await?;
+        let data = T::deserialize(&raw).map_err(|source| {
+            let error = WattpadError::ParseError {
+                source,
+                endpoint: path,
+                type_name: std::any::type_name::<T>(),
+            };
+            #[cfg(feature = "metrics")]
+            record_error(&error);
+            error
+        })?;
+        Ok(RawApiResponse { data, raw })
+    }
+
+    /// Executes a request against a list endpoint and returns the items of its collection.
+    ///
+    /// This is a convenience wrapper around `execute_paginated` for endpoints where the
+    /// pagination metadata is not exposed to the caller.
+    pub(crate) async fn execute_list<T: serde::de::DeserializeOwned>(
+        self,
+    ) -> Result<Vec<T>, WattpadError> {
+        Ok(self.execute_paginated().await?.items)
+    }
+
+    /// Executes a request against a list endpoint and returns a single page of results.
+    ///
+    /// If no collection key was set, the response body is expected to be a bare JSON array.
+    /// Wattpad may omit the collection entirely when there are no results, so a missing or
+    /// `null` collection is treated as an empty page rather than an error.
+    pub(crate) async fn execute_paginated<T: serde::de::DeserializeOwned>(
+        self,
+    ) -> Result<PaginatedResponse<T>, WattpadError> {
+        let (key, limit, offset) = (self.collection, self.limit, self.offset);
+        let endpoint = self.path.clone();
+        let Some(key) = key else {
+            return Ok(PaginatedResponse {
+                items: self.execute().await?,
+                total: None,
+                limit,
+                offset,
+                next_offset: None,
+            });
+        };
+
+        let mut envelope = self.execute::<ListEnvelope>().await?;
+        let items: Vec<T> = match envelope.collections.remove(key) {
+            Some(serde_json::Value::Null) | None => Vec::new(),
+            Some(items) => {
+                serde_json::from_value(items).map_err(|source| WattpadError::ParseError {
+                    source,
+                    endpoint,
+                    type_name: std::any::type_name::<Vec<T>>(),
+                })?
+            }
+        };
+
+        // Prefer the offset embedded in the API's `nextUrl`, falling back to computing it
+        // from the reported total when the URL is absent.
+        let next_offset = match envelope.next_url.as_deref() {
+            Some(next_url) => offset_from_url(next_url),
+            None => {
+                let consumed = offset.unwrap_or(0) as u64 + items.len() as u64;
+                match envelope.total {
+                    Some(total) if !items.is_empty() && consumed < total => {
+                        u32::try_from(consumed).ok()
+                    }
+                    _ => None,
+                }
+            }
+        };
+
+        Ok(PaginatedResponse {
+            items,
+            total: envelope.total,
+            limit,
+            offset,
+            next_offset,
+        })
+    }
+
+    /// Executes the request and discards the response body.
+    ///
+    /// This is intended for write operations (e.g., voting) whose successful response
+    /// carries no meaningful data.
+    pub(crate) async fn execute_empty(self) -> Result<(), WattpadError> {
+        let response = self.send().await?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(error_from_response(response).await)
+        }
     }
 
     /// Executes the request and returns the raw response body as a `String`.
     pub(crate) async fn execute_raw_text(self) -> Result<String, WattpadError> {
-        self.check_endpoint_auth()?;
-
-        let url = format!("https://www.wattpad.com{}", self.path);
-        let response = self
-            .client
-            .request(self.method, &url)
-            .query(&self.params)
-            .send()
-            .await?;
+        let response = self.send().await?;
 
         if response.status().is_success() {
             Ok(response.text().await?)
         } else {
-            let error_response = response.json::<ApiErrorResponse>().await?;
-            Err(error_response.into())
+            Err(error_from_response(response).await)
         }
     }
 
@@ -379,21 +1345,12 @@ impl<'a> WattpadRequestBuilder<'a> {
     ///
     /// This method is ideal for downloading files or other binary content.
     pub(crate) async fn execute_bytes(self) -> Result<Bytes, WattpadError> {
-        self.check_endpoint_auth()?;
-
-        let url = format!("https://www.wattpad.com{}", self.path);
-        let response = self
-            .client
-            .request(self.method, &url)
-            .query(&self.params)
-            .send()
-            .await?;
+        let response = self.send().await?;
 
         if response.status().is_success() {
             Ok(response.bytes().await?)
         } else {
-            let error_response = response.json::<ApiErrorResponse>().await?;
-            Err(error_response.into())
+            Err(error_from_response(response).await)
         }
     }
 }
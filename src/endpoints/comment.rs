@@ -0,0 +1,266 @@
+use crate::client::WattpadRequestBuilder;
+use crate::field::CommentField;
+use crate::types::{CommentContext, CommentResponse};
+use crate::{PaginatedResponse, PaginationParams, WattpadError};
+use serde::Serialize;
+
+/// The JSON payload for a new comment.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize)]
+struct NewComment<'a> {
+    text: &'a str,
+    paragraph: Option<u32>,
+}
+
+define_sub_client!(
+    /// Contains methods for comment-related API endpoints.
+    ///
+    /// This client provides reading, posting, deleting and voting on comments. Comments on a
+    /// whole part can also be listed with `StoryClient::get_part_comments`.
+    CommentClient, "comment"
+);
+
+impl CommentClient {
+    /// Returns a single comment.
+    ///
+    /// # Arguments
+    /// * `comment_id` - The unique identifier of the comment.
+    /// * `fields` - An optional slice of `CommentField` specifying which fields to retrieve.
+    ///   If `None`, a default set of fields will be requested.
+    ///
+    /// # Returns
+    /// A `Result` containing the `CommentResponse` on success.
+    ///
+    /// # Errors
+    /// Returns a `WattpadError` if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::{WattpadClient, field::CommentField};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// let fields = &[CommentField::Text, CommentField::ReplyCount];
+    ///
+    /// let comment = client.comment.get_comment(42, Some(fields)).await?;
+    /// println!("{:?} ({:?} replies)", comment.text, comment.reply_count);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_comment(
+        &self,
+        comment_id: u64,
+        fields: Option<&[CommentField]>,
+    ) -> Result<CommentResponse, WattpadError> {
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::GET,
+            &format!("/api/v3/comments/{}", comment_id),
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .fields(fields)?
+            .execute()
+            .await
+    }
+
+    /// Posts a new comment as the authenticated user.
+    ///
+    /// # Arguments
+    /// * `context` - Where to post the comment: on a part, on one of its paragraphs, or as a
+    ///   reply to another comment.
+    /// * `text` - The text of the comment. Must not be empty or only whitespace.
+    ///
+    /// # Returns
+    /// A `Result` containing the created `CommentResponse`, including its new ID, on success.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::InvalidInput` if `text` is empty,
+    /// `WattpadError::AuthenticationRequired` if the client is not authenticated, or another
+    /// `WattpadError` if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::WattpadClient;
+    /// # use wp_mini::types::CommentContext;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// client.authenticate("username", "password").await?;
+    ///
+    /// let comment = client
+    ///     .comment
+    ///     .post_comment(CommentContext::Part(87654321), "Loved this chapter!".to_string())
+    ///     .await?;
+    /// println!("Posted comment {:?}", comment.id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn post_comment(
+        &self,
+        context: CommentContext,
+        text: String,
+    ) -> Result<CommentResponse, WattpadError> {
+        if text.trim().is_empty() {
+            return Err(WattpadError::InvalidInput {
+                field: "text".to_string(),
+                reason: "The comment text must not be empty.".to_string(),
+            });
+        }
+
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::POST,
+            &context.path(),
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .requires_auth()
+            .fields::<CommentField>(None)?
+            .json_body(&NewComment {
+                text: &text,
+                paragraph: context.paragraph(),
+            })
+            .execute()
+            .await
+    }
+
+    /// Deletes a comment posted by the authenticated user.
+    ///
+    /// # Arguments
+    /// * `comment_id` - The unique identifier of the comment to delete.
+    ///
+    /// # Returns
+    /// An empty `Ok(())` on success.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::AuthenticationRequired` if the client is not authenticated, or
+    /// another `WattpadError` if the network request fails or the API returns an error
+    /// (e.g., the comment belongs to someone else).
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::WattpadClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// client.authenticate("username", "password").await?;
+    ///
+    /// client.comment.delete_comment(42).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_comment(&self, comment_id: u64) -> Result<(), WattpadError> {
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::DELETE,
+            &format!("/api/v3/comments/{}", comment_id),
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .requires_auth()
+            .execute_empty()
+            .await
+    }
+
+    /// Votes for a comment as the authenticated user.
+    ///
+    /// # Arguments
+    /// * `comment_id` - The unique identifier of the comment to vote for.
+    ///
+    /// # Returns
+    /// An empty `Ok(())` on success.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::AuthenticationRequired` if the client is not authenticated, or
+    /// another `WattpadError` if the network request fails or the API returns an error (e.g.,
+    /// the user has already voted for this comment).
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::WattpadClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// client.authenticate("username", "password").await?;
+    ///
+    /// client.comment.vote_comment(42).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn vote_comment(&self, comment_id: u64) -> Result<(), WattpadError> {
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::POST,
+            &format!("/api/v3/comments/{}/votes", comment_id),
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .requires_auth()
+            .execute_empty()
+            .await
+    }
+
+    /// Returns the replies to a comment.
+    ///
+    /// # Arguments
+    /// * `comment_id` - The unique identifier of the comment.
+    /// * `pagination` - An optional `PaginationParams` giving the maximum number of replies to
+    ///   return and how many to skip.
+    ///
+    /// # Returns
+    /// A `Result` containing a `PaginatedResponse<CommentResponse>` on success. The page is
+    /// empty if the comment has no replies.
+    ///
+    /// # Errors
+    /// Returns a `WattpadError` if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::WattpadClient;
+    /// # use wp_mini::PaginationParams;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    ///
+    /// let first_page = PaginationParams::new(20, 0);
+    /// for reply in client.comment.get_replies(42, Some(first_page)).await? {
+    ///     println!("{:?}", reply.text);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_replies(
+        &self,
+        comment_id: u64,
+        pagination: Option<PaginationParams>,
+    ) -> Result<PaginatedResponse<CommentResponse>, WattpadError> {
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::GET,
+            &format!("/api/v3/comments/{}/replies", comment_id),
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .collection("comments")
+            .fields::<CommentField>(None)?
+            .pagination(pagination)
+            .execute_paginated()
+            .await
+    }
+}
@@ -0,0 +1,62 @@
+use crate::client::WattpadRequestBuilder;
+use crate::field::LanguageField;
+use crate::types::LanguageResponse;
+use crate::WattpadError;
+
+define_sub_client!(
+    /// Contains methods for language-related API endpoints.
+    ///
+    /// This client provides the list of languages Wattpad supports, whose IDs are used to
+    /// filter stories and searches by language.
+    LanguageClient, "language"
+);
+
+impl LanguageClient {
+    /// Returns every language supported by Wattpad.
+    ///
+    /// # Arguments
+    /// * `fields` - An optional slice of `LanguageField` specifying which fields to retrieve.
+    ///   If `None`, a default set of fields will be requested.
+    ///
+    /// # Returns
+    /// A `Result` containing a `Vec<LanguageResponse>` on success.
+    ///
+    /// # Errors
+    /// Returns a `WattpadError` if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::{WattpadClient, field::LanguageField};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// let fields = &[LanguageField::Id, LanguageField::Name];
+    ///
+    /// let languages = client.language.get_languages(Some(fields)).await?;
+    ///
+    /// for language in languages {
+    ///     println!("{:?}: {:?}", language.id, language.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_languages(
+        &self,
+        fields: Option<&[LanguageField]>,
+    ) -> Result<Vec<LanguageResponse>, WattpadError> {
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::GET,
+            "/api/v3/languages",
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .collection("languages")
+            .fields(fields)?
+            .execute_list()
+            .await
+    }
+}
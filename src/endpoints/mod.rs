@@ -4,5 +4,60 @@
 //! grouping related functionalities together. For example, `user` handles
 //! user-related actions, while `story` manages story and part-related actions.
 
+/// Defines an endpoint sub-client struct holding the state shared with the main client,
+/// along with its `with_timeout` method.
+///
+/// `$field` is the name of the sub-client's field on `WattpadClient`, used in the examples.
+macro_rules! define_sub_client {
+    ($(#[$meta:meta])* $name:ident, $field:literal) => {
+        $(#[$meta])*
+        #[derive(Clone)]
+        pub struct $name {
+            /// The shared `reqwest` client for making HTTP requests.
+            pub(crate) http: reqwest::Client,
+            /// A flag indicating whether the main client is authenticated.
+            pub(crate) is_authenticated: std::sync::Arc<std::sync::atomic::AtomicBool>,
+            /// The base URL all API paths are resolved against.
+            pub(crate) base_url: std::sync::Arc<String>,
+            /// An optional timeout applied to every request made through this client.
+            pub(crate) timeout: Option<std::time::Duration>,
+            /// The retry policy configured on the main client, if any.
+            pub(crate) retry: Option<$crate::client::RetryPolicy>,
+            /// The response cache shared with the main client, if enabled.
+            pub(crate) cache: $crate::cache::CacheSlot,
+        }
+
+        impl $name {
+            /// Returns a copy of this client that applies `duration` as a timeout to each request.
+            ///
+            /// The copy shares the connection pool and authentication state with the original. If a
+            /// client-wide timeout is also configured, whichever is shorter applies.
+            ///
+            /// # Examples
+            ///
+            /// ```no_run
+            /// # use std::time::Duration;
+            /// # use wp_mini::{WattpadClient, WattpadError};
+            /// # #[tokio::main]
+            /// # async fn main() -> Result<(), WattpadError> {
+            /// let client = WattpadClient::new();
+            #[doc = concat!("let ", $field, " = client.", $field, ".with_timeout(Duration::from_secs(5));")]
+            /// # Ok(())
+            /// # }
+            /// ```
+            pub fn with_timeout(&self, duration: std::time::Duration) -> Self {
+                Self {
+                    timeout: Some(duration),
+                    ..self.clone()
+                }
+            }
+        }
+    };
+}
+
+pub mod comment;
+pub mod language;
+pub mod reading_list;
+pub mod search;
 pub mod story;
 pub mod user;
\ No newline at end of file
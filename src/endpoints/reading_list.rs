@@ -0,0 +1,240 @@
+use crate::client::WattpadRequestBuilder;
+use crate::field::ReadingListField;
+use crate::types::{checked_story_id, ReadingListResponse, StoryId};
+use crate::WattpadError;
+use serde::Serialize;
+
+/// The maximum number of characters Wattpad allows in a reading list name.
+const MAX_LIST_NAME_LENGTH: usize = 100;
+
+/// The JSON payload for a new reading list.
+#[derive(Serialize)]
+struct NewReadingList<'a> {
+    name: &'a str,
+}
+
+define_sub_client!(
+    /// Contains methods for reading list-related API endpoints.
+    ///
+    /// This client provides access to fetching reading lists and managing their contents.
+    ReadingListClient, "reading_list"
+);
+
+impl ReadingListClient {
+    /// Returns detailed information about a reading list.
+    ///
+    /// # Arguments
+    /// * `list_id` - The unique identifier of the reading list to fetch.
+    /// * `fields` - An optional slice of `ReadingListField` specifying which fields to retrieve.
+    ///   If `None`, a default set of fields will be requested.
+    ///
+    /// # Returns
+    /// A `Result` containing a `ReadingListResponse` struct with the list's data on success.
+    ///
+    /// # Errors
+    /// Returns a `WattpadError` if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::{WattpadClient, field::{ReadingListField, StoryField}};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// let fields = &[
+    ///     ReadingListField::Name,
+    ///     ReadingListField::Stories(vec![StoryField::Id, StoryField::Title]),
+    /// ];
+    ///
+    /// let list = client.reading_list.get_reading_list(123456789, Some(fields)).await?;
+    ///
+    /// println!("List: {:?}", list.name);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_reading_list(
+        &self,
+        list_id: u64,
+        fields: Option<&[ReadingListField]>,
+    ) -> Result<ReadingListResponse, WattpadError> {
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::GET,
+            &format!("/api/v3/lists/{}", list_id),
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .fields(fields)?
+            .execute()
+            .await
+    }
+
+    /// Adds a story to one of the authenticated user's reading lists.
+    ///
+    /// # Arguments
+    /// * `list_id` - The unique identifier of the reading list.
+    /// * `story_id` - The unique identifier of the story to add.
+    ///
+    /// # Returns
+    /// An empty `Ok(())` on success.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::AuthenticationRequired` if the client is not authenticated,
+    /// `WattpadError::AlreadyExists` if the story is already in the list, or another
+    /// `WattpadError` if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::WattpadClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// client.authenticate("username", "password").await?;
+    ///
+    /// client.reading_list.add_story(123456789, 12345678).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn add_story(
+        &self,
+        list_id: u64,
+        story_id: impl Into<StoryId>,
+    ) -> Result<(), WattpadError> {
+        let story_id = checked_story_id(story_id)?;
+
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::POST,
+            &format!("/api/v3/lists/{}/stories/{}", list_id, story_id),
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .requires_auth()
+            .execute_empty()
+            .await
+            .map_err(|e| match e {
+                WattpadError::HttpError { status: 409, .. } => WattpadError::AlreadyExists,
+                e => e,
+            })
+    }
+
+    /// Removes a story from one of the authenticated user's reading lists.
+    ///
+    /// # Arguments
+    /// * `list_id` - The unique identifier of the reading list.
+    /// * `story_id` - The unique identifier of the story to remove.
+    ///
+    /// # Returns
+    /// An empty `Ok(())` on success.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::AuthenticationRequired` if the client is not authenticated,
+    /// `WattpadError::StoryNotInList` if the story is not in the list, or another
+    /// `WattpadError` if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::WattpadClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// client.authenticate("username", "password").await?;
+    ///
+    /// client.reading_list.remove_story(123456789, 12345678).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove_story(
+        &self,
+        list_id: u64,
+        story_id: impl Into<StoryId>,
+    ) -> Result<(), WattpadError> {
+        let story_id = checked_story_id(story_id)?;
+
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::DELETE,
+            &format!("/api/v3/lists/{}/stories/{}", list_id, story_id),
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .requires_auth()
+            .execute_empty()
+            .await
+            .map_err(|e| match e {
+                WattpadError::HttpError { status: 404, .. } => WattpadError::StoryNotInList,
+                e => e,
+            })
+    }
+
+    /// Creates a new reading list for the authenticated user.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the new reading list. Must be non-empty and at most
+    ///   100 characters long.
+    ///
+    /// # Returns
+    /// A `Result` containing the created `ReadingListResponse`, including its newly
+    /// assigned ID, on success.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::InvalidInput` if `name` is empty or too long,
+    /// `WattpadError::AuthenticationRequired` if the client is not authenticated, or another
+    /// `WattpadError` if the network request fails.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::WattpadClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// client.authenticate("username", "password").await?;
+    ///
+    /// let list = client.reading_list.create_reading_list("Favourites").await?;
+    /// println!("Created list with ID {:?}", list.id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_reading_list(&self, name: &str) -> Result<ReadingListResponse, WattpadError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(WattpadError::InvalidInput {
+                field: "name".to_string(),
+                reason: "The reading list name must not be empty.".to_string(),
+            });
+        }
+        if name.chars().count() > MAX_LIST_NAME_LENGTH {
+            return Err(WattpadError::InvalidInput {
+                field: "name".to_string(),
+                reason: format!(
+                    "The reading list name must be at most {} characters long.",
+                    MAX_LIST_NAME_LENGTH
+                ),
+            });
+        }
+
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::POST,
+            "/api/v3/users/me/lists",
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .requires_auth()
+            .fields::<ReadingListField>(None)?
+            .json_body(&NewReadingList { name })
+            .execute()
+            .await
+    }
+}
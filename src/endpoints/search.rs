@@ -0,0 +1,248 @@
+use crate::client::WattpadRequestBuilder;
+use crate::field::{StoryField, UserField};
+use crate::types::{SearchQuery, SortOrder, StoryResponse, StorySortField, UserResponse};
+use crate::{PaginatedResponse, PaginationParams, WattpadError};
+#[cfg(feature = "stream")]
+use crate::PaginationStream;
+
+define_sub_client!(
+    /// Contains methods for search-related API endpoints.
+    ///
+    /// This client provides keyword search across Wattpad's public stories and users.
+    SearchClient, "search"
+);
+
+impl SearchClient {
+    /// Searches for stories matching a query string.
+    ///
+    /// # Arguments
+    /// * `query` - The search terms to match against story titles, descriptions, and tags.
+    /// * `fields` - An optional slice of `StoryField` specifying which fields to retrieve.
+    ///   If `None`, a comprehensive list of all known fields will be requested by default.
+    /// * `sort_by` - An optional field and direction to order the stories by. If `None`, the
+    ///   results are ordered by relevance.
+    /// * `pagination` - An optional `PaginationParams` giving the maximum number of stories to
+    ///   return and how many to skip.
+    ///
+    /// # Returns
+    /// A `Result` containing a `PaginatedResponse<StoryResponse>` with the matching stories
+    /// on success.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::InvalidInput` if `query` is empty or only whitespace, or another
+    /// `WattpadError` if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::{WattpadClient, field::StoryField};
+    /// # use wp_mini::PaginationParams;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// let fields = &[StoryField::Id, StoryField::Title];
+    ///
+    /// let first_page = PaginationParams::new(10, 0);
+    /// let results = client
+    ///     .search
+    ///     .search_stories("dragons", Some(fields), None, Some(first_page))
+    ///     .await?;
+    ///
+    /// for story in results {
+    ///     println!("Found: {:?}", story.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_stories(
+        &self,
+        query: &str,
+        fields: Option<&[StoryField]>,
+        sort_by: Option<(StorySortField, SortOrder)>,
+        pagination: Option<PaginationParams>,
+    ) -> Result<PaginatedResponse<StoryResponse>, WattpadError> {
+        let mut search = SearchQuery::new().keyword(query);
+        if let Some((field, order)) = sort_by {
+            search = search.sort_by(field, order);
+        }
+        self.search_stories_with(search, fields, pagination).await
+    }
+
+    /// Returns a `Stream` over every story matching a query string.
+    ///
+    /// Results are fetched lazily with `search_stories`, one page of `page_size` items at
+    /// a time. Requires the `stream` feature.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use futures_util::StreamExt;
+    /// # use wp_mini::WattpadClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    ///
+    /// let mut results = client.search.stream_search_stories("dragons", None, 50);
+    /// while let Some(story) = results.next().await {
+    ///     println!("{:?}", story?.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "stream")]
+    pub fn stream_search_stories<'a>(
+        &'a self,
+        query: &'a str,
+        fields: Option<&'a [StoryField]>,
+        page_size: u32,
+    ) -> PaginationStream<'a, StoryResponse> {
+        PaginationStream::new(page_size, move |limit, offset| {
+            let page = PaginationParams::new(limit, offset);
+            Box::pin(self.search_stories(query, fields, None, Some(page)))
+        })
+    }
+
+    /// Searches for stories using a structured `SearchQuery`.
+    ///
+    /// This is a type-safe alternative to `search_stories` that allows combining the
+    /// search terms with filters such as language, category, and completion status.
+    ///
+    /// # Arguments
+    /// * `query` - The `SearchQuery` describing the search terms and filters.
+    /// * `fields` - An optional slice of `StoryField` specifying which fields to retrieve.
+    ///   If `None`, a comprehensive list of all known fields will be requested by default.
+    /// * `pagination` - An optional `PaginationParams` giving the maximum number of stories to
+    ///   return and how many to skip.
+    ///
+    /// # Returns
+    /// A `Result` containing a `PaginatedResponse<StoryResponse>` with the matching stories
+    /// on success.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::InvalidInput` if the query has no keyword or an empty one, or
+    /// another `WattpadError` if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::{WattpadClient, field::StoryField};
+    /// # use wp_mini::types::{category_ids, SearchQuery};
+    /// # use wp_mini::PaginationParams;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// let query = SearchQuery::new()
+    ///     .keyword("dragons")
+    ///     .category(category_ids::FANTASY)
+    ///     .mature(false);
+    ///
+    /// let first_page = PaginationParams::new(10, 0);
+    /// let results = client.search.search_stories_with(query, None, Some(first_page)).await?;
+    /// println!("Found {} stories", results.items.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_stories_with(
+        &self,
+        query: SearchQuery,
+        fields: Option<&[StoryField]>,
+        pagination: Option<PaginationParams>,
+    ) -> Result<PaginatedResponse<StoryResponse>, WattpadError> {
+        let keyword = query.keyword_str().unwrap_or_default().trim().to_string();
+        if keyword.is_empty() {
+            return Err(WattpadError::InvalidInput {
+                field: "query".to_string(),
+                reason: "The search query must not be empty.".to_string(),
+            });
+        }
+        let query = query.keyword(keyword);
+
+        let mut request = WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::GET,
+            "/api/v3/stories",
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .collection("stories")
+            .fields(fields)?;
+        for (key, value) in query.query_pairs() {
+            request = request.param(key, Some(value));
+        }
+
+        request
+            .pagination(pagination)
+            .execute_paginated()
+            .await
+    }
+
+    /// Searches for users by username or display name.
+    ///
+    /// # Arguments
+    /// * `query` - The search terms to match against usernames and display names. Must be at
+    ///   least 2 characters long.
+    /// * `fields` - An optional slice of `UserField` specifying which fields to retrieve.
+    ///   If `None`, a default set of fields will be requested.
+    /// * `pagination` - An optional `PaginationParams` giving the maximum number of users to
+    ///   return and how many to skip.
+    ///
+    /// # Returns
+    /// A `Result` containing a `PaginatedResponse<UserResponse>` with the matching users
+    /// on success.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::InvalidInput` if `query` is shorter than 2 characters (ignoring
+    /// surrounding whitespace), or another `WattpadError` if the network request fails, the
+    /// API returns an error, or a requested field requires authentication when the client is
+    /// unauthenticated.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::{WattpadClient, field::UserField};
+    /// # use wp_mini::PaginationParams;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// let fields = &[UserField::Username, UserField::NumFollowers];
+    ///
+    /// let first_page = PaginationParams::new(10, 0);
+    /// let users = client.search.search_users("wattpad", Some(fields), Some(first_page)).await?;
+    ///
+    /// for user in users {
+    ///     println!("{:?} ({:?} followers)", user.username, user.num_followers);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_users(
+        &self,
+        query: &str,
+        fields: Option<&[UserField]>,
+        pagination: Option<PaginationParams>,
+    ) -> Result<PaginatedResponse<UserResponse>, WattpadError> {
+        let query = query.trim();
+        if query.chars().count() < 2 {
+            return Err(WattpadError::InvalidInput {
+                field: "query".to_string(),
+                reason: "The search query must be at least 2 characters long.".to_string(),
+            });
+        }
+
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::GET,
+            "/api/v3/users",
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .collection("users")
+            .fields(fields)?
+            .param("query", Some(query))
+            .pagination(pagination)
+            .execute_paginated()
+            .await
+    }
+}
@@ -1,27 +1,57 @@
-use crate::client::WattpadRequestBuilder;
-use crate::field::{PartField, StoryField};
-use crate::types::{PartContentResponse, PartResponse, StoryResponse};
-use crate::WattpadError;
-use std::sync::atomic::AtomicBool;
-use std::sync::Arc;
+use crate::client::{download, image_form, WattpadRequestBuilder};
+use crate::field::{AnnouncementField, CommentField, PartField, StoryField};
+use crate::types::{
+    checked_part_id, checked_story_id, AnnouncementResponse, CommentResponse,
+    PartContentResponse, PartId, PartResponse, PartStubResponse, PartSortField, RawApiResponse,
+    ReadingPositionResponse, ReportReason, SortOrder, StoryFilter, StoryId, StoryResponse,
+    WritingStatsResponse,
+};
+use crate::{PaginatedResponse, PaginationParams, WattpadError};
+#[cfg(feature = "epub")]
+use crate::epub;
+use crate::field::PartStubField;
+#[cfg(feature = "epub")]
+use crate::field::UserStubField;
+#[cfg(feature = "stream")]
+use crate::PaginationStream;
 use bytes::Bytes;
+use futures_util::future::{join_all, try_join_all};
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use serde::Serialize;
 
-/// Contains methods for story-related API endpoints.
-///
-/// This client provides access to fetching information about stories, story parts,
-/// and their content in various formats.
-pub struct StoryClient {
-    /// The shared `reqwest` client for making HTTP requests.
-    pub(crate) http: reqwest::Client,
-    /// A flag indicating whether the main client is authenticated.
-    pub(crate) is_authenticated: Arc<AtomicBool>,
+/// The number of parts fetched per request when streaming a story's parts.
+#[cfg(feature = "stream")]
+const STORY_PARTS_PAGE_SIZE: u32 = 50;
+
+/// Maps a bare 404 from a part endpoint to `WattpadError::StoryPartNotFound`.
+fn part_not_found(e: WattpadError) -> WattpadError {
+    match e {
+        WattpadError::HttpError { status: 404, .. } => WattpadError::StoryPartNotFound,
+        e => e,
+    }
+}
+
+/// The JSON payload for a reading position update.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReadingPositionUpdate {
+    part_id: PartId,
+    position: u64,
 }
 
+define_sub_client!(
+    /// Contains methods for story-related API endpoints.
+    ///
+    /// This client provides access to fetching information about stories, story parts,
+    /// and their content in various formats.
+    StoryClient, "story"
+);
+
 impl StoryClient {
     /// Returns detailed information about a story.
     ///
     /// # Arguments
-    /// * `story_id` - The unique identifier of the story to fetch.
+    /// * `story_id` - The unique identifier of the story to fetch, as a `StoryId` or a `u64`.
     /// * `fields` - An optional slice of `StoryField` specifying which fields to retrieve.
     ///   If `None`, a comprehensive list of all known fields will be requested by default.
     ///
@@ -29,40 +59,195 @@ impl StoryClient {
     /// A `Result` containing a `StoryResponse` struct with the story's metadata on success.
     ///
     /// # Errors
-    /// Returns a `WattpadError` if the network request fails or the API returns an error.
+    /// Returns `WattpadError::InvalidInput` if `story_id` is zero, or another `WattpadError`
+    /// if the network request fails or the API returns an error.
     ///
     /// # Examples
     /// ```no_run
-    /// # use wattpad::{WattpadClient, field::StoryField};
+    /// # use wp_mini::{WattpadClient, field::StoryField};
     /// # #[tokio::main]
-    /// # async fn main() -> Result<(), wattpad::WattpadError> {
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
     /// let client = WattpadClient::new();
     /// let story_id = 12345678; // Example story ID
     /// let fields = &[StoryField::Title, StoryField::VoteCount];
     ///
     /// let story_info = client.story.get_story_info(story_id, Some(fields)).await?;
     ///
-    /// println!("Title: {}", story_info.title);
-    /// println!("Votes: {}", story_info.vote_count);
+    /// println!("Title: {:?}", story_info.title);
+    /// println!("Votes: {:?}", story_info.vote_count);
     /// # Ok(())
     /// # }
     /// ```
     pub async fn get_story_info(
         &self,
-        story_id: u64,
+        story_id: impl Into<StoryId>,
         fields: Option<&[StoryField]>,
     ) -> Result<StoryResponse, WattpadError> {
+        let story_id = checked_story_id(story_id)?;
+
         WattpadRequestBuilder::new(
             &self.http,
             &self.is_authenticated,
+            &self.base_url,
             reqwest::Method::GET,
             &format!("/api/v3/stories/{}", story_id),
         )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
             .fields(fields)?
             .execute()
             .await
     }
 
+    /// Returns detailed information about a story, along with the raw JSON it was parsed from.
+    ///
+    /// This behaves like `get_story_info`, but also keeps the complete response body, which
+    /// helps when the API returns data that `StoryResponse` does not model.
+    ///
+    /// # Arguments
+    /// * `story_id` - The unique identifier of the story to fetch, as a `StoryId` or a `u64`.
+    /// * `fields` - An optional slice of `StoryField` specifying which fields to retrieve.
+    ///   If `None`, a comprehensive list of all known fields will be requested by default.
+    ///
+    /// # Returns
+    /// A `Result` containing a `RawApiResponse<StoryResponse>` on success.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::InvalidInput` if `story_id` is zero, or another `WattpadError`
+    /// if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::{WattpadClient, field::StoryField};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    ///
+    /// let response = client.story.get_story_info_raw(12345678, Some(&[StoryField::Title])).await?;
+    /// assert_eq!(response.raw["title"].as_str(), response.data.title.as_deref());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_story_info_raw(
+        &self,
+        story_id: impl Into<StoryId>,
+        fields: Option<&[StoryField]>,
+    ) -> Result<RawApiResponse<StoryResponse>, WattpadError> {
+        let story_id = checked_story_id(story_id)?;
+
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::GET,
+            &format!("/api/v3/stories/{}", story_id),
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .fields(fields)?
+            .execute_with_raw()
+            .await
+    }
+
+    /// Returns detailed information about several stories, fetched concurrently.
+    ///
+    /// Stories that do not exist are reported as `None` rather than failing the whole batch.
+    ///
+    /// # Arguments
+    /// * `story_ids` - The unique identifiers of the stories to fetch.
+    /// * `fields` - An optional slice of `StoryField` specifying which fields to retrieve.
+    ///   If `None`, a comprehensive list of all known fields will be requested by default.
+    ///
+    /// # Returns
+    /// A `Result` containing one entry per ID, in the same order as `story_ids`: `Some` with the
+    /// story's metadata, or `None` if the API reported `WattpadError::StoryNotFound`.
+    ///
+    /// # Errors
+    /// Returns the first `WattpadError` other than `StoryNotFound` encountered in the batch.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::{WattpadClient, field::StoryField};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// let fields = &[StoryField::Title];
+    ///
+    /// let stories = client.story.get_stories_info(&[12345678, 87654321], Some(fields)).await?;
+    ///
+    /// for story in stories.into_iter().flatten() {
+    ///     println!("Title: {:?}", story.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_stories_info(
+        &self,
+        story_ids: &[impl Into<StoryId> + Copy],
+        fields: Option<&[StoryField]>,
+    ) -> Result<Vec<Option<StoryResponse>>, WattpadError> {
+        let story_ids = story_ids
+            .iter()
+            .map(|&id| checked_story_id(id))
+            .collect::<Result<Vec<_>, _>>()?;
+        let requests = story_ids.into_iter().map(|id| self.get_story_info(id, fields));
+
+        join_all(requests)
+            .await
+            .into_iter()
+            .map(|result| match result {
+                Ok(story) => Ok(Some(story)),
+                Err(WattpadError::StoryNotFound) => Ok(None),
+                Err(e) => Err(e),
+            })
+            .collect()
+    }
+
+    /// Returns detailed information about several stories, fetched concurrently.
+    ///
+    /// Unlike `get_stories_info`, a missing story is treated as an error.
+    ///
+    /// # Arguments
+    /// * `story_ids` - The unique identifiers of the stories to fetch.
+    /// * `fields` - An optional slice of `StoryField` specifying which fields to retrieve.
+    ///   If `None`, a comprehensive list of all known fields will be requested by default.
+    ///
+    /// # Returns
+    /// A `Result` containing the stories in the same order as `story_ids`.
+    ///
+    /// # Errors
+    /// Returns the first `WattpadError` encountered in the batch, including `StoryNotFound`.
+    /// The remaining requests are abandoned as soon as one fails.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::{WattpadClient, field::StoryField};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// let fields = &[StoryField::Title];
+    ///
+    /// let stories = client.story.get_stories_info_strict(&[12345678, 87654321], Some(fields)).await?;
+    /// println!("Fetched {} stories", stories.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_stories_info_strict(
+        &self,
+        story_ids: &[impl Into<StoryId> + Copy],
+        fields: Option<&[StoryField]>,
+    ) -> Result<Vec<StoryResponse>, WattpadError> {
+        let story_ids = story_ids
+            .iter()
+            .map(|&id| checked_story_id(id))
+            .collect::<Result<Vec<_>, _>>()?;
+        let requests = story_ids.into_iter().map(|id| self.get_story_info(id, fields));
+
+        try_join_all(requests).await
+    }
+
     /// Returns detailed information about a single story part.
     ///
     /// # Arguments
@@ -74,38 +259,46 @@ impl StoryClient {
     /// A `Result` containing a `PartResponse` struct with the part's metadata on success.
     ///
     /// # Errors
-    /// Returns a `WattpadError` if the network request fails or the API returns an error.
+    /// Returns `WattpadError::StoryPartNotFound` if the part does not exist, or another
+    /// `WattpadError` if the network request fails or the API returns an error.
     ///
     /// # Examples
     /// ```no_run
-    /// # use wattpad::{WattpadClient, field::PartField};
+    /// # use wp_mini::{WattpadClient, field::PartField};
     /// # #[tokio::main]
-    /// # async fn main() -> Result<(), wattpad::WattpadError> {
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
     /// let client = WattpadClient::new();
     /// let part_id = 87654321; // Example part ID
-    /// let fields = &[PartField::Title, PartField::WordCount];
+    /// let fields = &[PartField::Title, PartField::VoteCount];
     ///
     /// let part_info = client.story.get_part_info(part_id, Some(fields)).await?;
     ///
-    /// println!("Part Title: {}", part_info.title);
-    /// println!("Word Count: {}", part_info.word_count);
+    /// println!("Part Title: {:?}", part_info.title);
+    /// println!("Votes: {:?}", part_info.vote_count);
     /// # Ok(())
     /// # }
     /// ```
     pub async fn get_part_info(
         &self,
-        part_id: u64,
+        part_id: impl Into<PartId>,
         fields: Option<&[PartField]>,
     ) -> Result<PartResponse, WattpadError> {
+        let part_id = checked_part_id(part_id)?;
+
         WattpadRequestBuilder::new(
             &self.http,
             &self.is_authenticated,
+            &self.base_url,
             reqwest::Method::GET,
             &format!("/api/v3/story_parts/{}", part_id),
         )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
             .fields(fields)?
             .execute()
             .await
+            .map_err(part_not_found)
     }
 
     /// Fetches the raw text content of a single story part.
@@ -119,13 +312,14 @@ impl StoryClient {
     /// A `Result` containing a `String` with the raw story text on success.
     ///
     /// # Errors
-    /// Returns a `WattpadError` if the network request fails.
+    /// Returns `WattpadError::StoryPartNotFound` if the part does not exist, or another
+    /// `WattpadError` if the network request fails.
     ///
     /// # Examples
     /// ```no_run
-    /// # use wattpad::WattpadClient;
+    /// # use wp_mini::WattpadClient;
     /// # #[tokio::main]
-    /// # async fn main() -> Result<(), wattpad::WattpadError> {
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
     /// let client = WattpadClient::new();
     /// let part_id = 87654321;
     ///
@@ -134,17 +328,83 @@ impl StoryClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_part_content_raw(&self, part_id: u64) -> Result<String, WattpadError> {
+    pub async fn get_part_content_raw(
+        &self,
+        part_id: impl Into<PartId>,
+    ) -> Result<String, WattpadError> {
+        let part_id = checked_part_id(part_id)?;
+
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::GET,
+            "/apiv2/",
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .param("m", Some("storytext"))
+            .param("id", Some(part_id))
+            .execute_raw_text()
+            .await
+            .map_err(part_not_found)
+    }
+
+    /// Fetches the content of a single story part as HTML.
+    ///
+    /// Unlike `get_part_content_raw`, which takes the storytext endpoint's default output,
+    /// this explicitly requests the `html` output format. The markup is kept as Wattpad
+    /// stores it: paragraphs (`<p>`), line breaks and formatting tags such as `<b>` and `<i>`,
+    /// along with images and their attributes. Use this to render a part faithfully;
+    /// `PartContent::to_plain_text` and `PartContent::to_markdown` convert the same markup to
+    /// simpler forms.
+    ///
+    /// # Arguments
+    /// * `part_id` - The unique identifier for the story part.
+    ///
+    /// # Returns
+    /// A `Result` containing a `String` with the part's HTML on success.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::StoryPartNotFound` if the part does not exist, or another
+    /// `WattpadError` if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::WattpadClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// let part_id = 87654321;
+    ///
+    /// let html = client.story.get_part_content_html(part_id).await?;
+    /// std::fs::write(format!("{}.html", part_id), html).expect("failed to write file");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_part_content_html(
+        &self,
+        part_id: impl Into<PartId>,
+    ) -> Result<String, WattpadError> {
+        let part_id = checked_part_id(part_id)?;
+
         WattpadRequestBuilder::new(
             &self.http,
             &self.is_authenticated,
+            &self.base_url,
             reqwest::Method::GET,
             "/apiv2/",
         )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
             .param("m", Some("storytext"))
             .param("id", Some(part_id))
+            .param("output", Some("html"))
             .execute_raw_text()
             .await
+            .map_err(part_not_found)
     }
 
     /// Fetches the content of a story part as a structured JSON object.
@@ -156,36 +416,109 @@ impl StoryClient {
     /// A `Result` containing a `PartContentResponse` struct with the parsed story content on success.
     ///
     /// # Errors
-    /// Returns a `WattpadError` if the network request fails or the JSON response cannot be parsed.
+    /// Returns `WattpadError::StoryPartNotFound` if the part does not exist, or another
+    /// `WattpadError` if the network request fails or the JSON response cannot be parsed.
     ///
     /// # Examples
     /// ```no_run
-    /// # use wattpad::WattpadClient;
+    /// # use wp_mini::WattpadClient;
     /// # #[tokio::main]
-    /// # async fn main() -> Result<(), wattpad::WattpadError> {
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
     /// let client = WattpadClient::new();
     /// let part_id = 87654321;
     ///
     /// let content_json = client.story.get_part_content_json(part_id).await?;
-    /// println!("Text from JSON: {}...", content_json.text.chars().take(100).collect::<String>());
+    /// println!("Text from JSON: {}...", content_json.text.unwrap_or_default().chars().take(100).collect::<String>());
     /// # Ok(())
     /// # }
     /// ```
     pub async fn get_part_content_json(
         &self,
-        part_id: u64,
+        part_id: impl Into<PartId>,
     ) -> Result<PartContentResponse, WattpadError> {
+        let part_id = checked_part_id(part_id)?;
+
         WattpadRequestBuilder::new(
             &self.http,
             &self.is_authenticated,
+            &self.base_url,
             reqwest::Method::GET,
             "/apiv2/",
         )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
             .param("m", Some("storytext"))
             .param("id", Some(part_id))
             .param("output", Some("json"))
             .execute()
             .await
+            .map_err(part_not_found)
+    }
+
+    /// Returns the comments posted on a single story part.
+    ///
+    /// This includes inline comments anchored to a specific paragraph, whose index is
+    /// available via the `paragraph` field.
+    ///
+    /// # Arguments
+    /// * `part_id` - The unique identifier of the story part.
+    /// * `fields` - An optional slice of `CommentField` specifying which fields to retrieve.
+    ///   If `None`, a default set of fields will be requested.
+    /// * `pagination` - An optional `PaginationParams` giving the maximum number of comments to
+    ///   return and how many to skip.
+    ///
+    /// # Returns
+    /// A `Result` containing a `PaginatedResponse<CommentResponse>` on success. The page is
+    /// empty if the part has no comments.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::StoryPartNotFound` if the part does not exist, or another
+    /// `WattpadError` if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::{WattpadClient, field::CommentField};
+    /// # use wp_mini::PaginationParams;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// let part_id = 87654321;
+    /// let fields = &[CommentField::Text, CommentField::Paragraph];
+    ///
+    /// let first_page = PaginationParams::new(20, 0);
+    /// let comments = client.story.get_part_comments(part_id, Some(fields), Some(first_page)).await?;
+    ///
+    /// for comment in comments {
+    ///     println!("[{:?}] {:?}", comment.paragraph, comment.text);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_part_comments(
+        &self,
+        part_id: impl Into<PartId>,
+        fields: Option<&[CommentField]>,
+        pagination: Option<PaginationParams>,
+    ) -> Result<PaginatedResponse<CommentResponse>, WattpadError> {
+        let part_id = checked_part_id(part_id)?;
+
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::GET,
+            &format!("/api/v3/story_parts/{}/comments", part_id),
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .collection("comments")
+            .fields(fields)?
+            .pagination(pagination)
+            .execute_paginated()
+            .await
+            .map_err(part_not_found)
     }
 
     /// Downloads the text content of an entire story as a single ZIP archive.
@@ -203,7 +536,7 @@ impl StoryClient {
     ///
     /// # Examples
     /// ```no_run
-    /// # use wattpad::WattpadClient;
+    /// # use wp_mini::WattpadClient;
     /// # use std::fs::File;
     /// # use std::io::Write;
     /// # #[tokio::main]
@@ -221,17 +554,1323 @@ impl StoryClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_story_content_zip(&self, story_id: u64) -> Result<Bytes, WattpadError> {
+    pub async fn get_story_content_zip(
+        &self,
+        story_id: impl Into<StoryId>,
+    ) -> Result<Bytes, WattpadError> {
+        let story_id = checked_story_id(story_id)?;
+
         WattpadRequestBuilder::new(
             &self.http,
             &self.is_authenticated,
+            &self.base_url,
             reqwest::Method::GET,
             "/apiv2/",
         )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
             .param("m", Some("storytext"))
             .param("group_id", Some(story_id))
             .param("output", Some("zip"))
             .execute_bytes()
             .await
     }
+
+    /// Downloads a story's cover image.
+    ///
+    /// The cover URL is looked up with `get_story_info`, then the image is fetched from
+    /// Wattpad's image CDN. The bytes are returned as served, whatever the image format.
+    ///
+    /// # Arguments
+    /// * `story_id` - The unique identifier of the story.
+    ///
+    /// # Returns
+    /// A `Result` containing the image as `Bytes` on success.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::MissingRequiredField` if the story has no cover URL, or another
+    /// `WattpadError` if either request fails.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::WattpadClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = WattpadClient::new();
+    /// let story_id = 12345678;
+    ///
+    /// let cover = client.story.download_cover_image(story_id).await?;
+    /// std::fs::write(format!("{}-cover.jpg", story_id), &cover)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_cover_image(
+        &self,
+        story_id: impl Into<StoryId>,
+    ) -> Result<Bytes, WattpadError> {
+        let story_id = checked_story_id(story_id)?;
+
+        let story = self.get_story_info(story_id, Some(&[StoryField::Cover])).await?;
+        let url = story
+            .cover
+            .filter(|url| !url.is_empty())
+            .ok_or_else(|| WattpadError::MissingRequiredField {
+                field: "cover".to_string(),
+                context: "Cannot download a cover image without a cover URL.".to_string(),
+            })?;
+
+        Ok(download(&self.http, &url).await?.bytes().await?)
+    }
+
+    /// Replaces the cover image of a story owned by the authenticated user.
+    ///
+    /// # Arguments
+    /// * `story_id` - The unique identifier of the story.
+    /// * `image_bytes` - The raw image data.
+    /// * `content_type` - The media type of the image: `image/jpeg`, `image/png` or
+    ///   `image/gif`.
+    ///
+    /// # Returns
+    /// A `Result` containing the updated `StoryResponse`, with the new `cover` URL, on success.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::InvalidInput` if `content_type` is not a supported image type,
+    /// `WattpadError::AuthenticationRequired` if the client is not authenticated, or another
+    /// `WattpadError` if the network request fails or the API returns an error (e.g., the
+    /// story belongs to someone else).
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bytes::Bytes;
+    /// # use wp_mini::WattpadClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// client.authenticate("username", "password").await?;
+    ///
+    /// let image = Bytes::from(std::fs::read("cover.jpg").unwrap());
+    /// let story = client.story.upload_cover(12345678, image, "image/jpeg").await?;
+    /// println!("New cover: {:?}", story.cover);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn upload_cover(
+        &self,
+        story_id: impl Into<StoryId>,
+        image_bytes: Bytes,
+        content_type: &str,
+    ) -> Result<StoryResponse, WattpadError> {
+        let story_id = checked_story_id(story_id)?;
+
+        let form = image_form(image_bytes, content_type, "cover")?;
+
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::POST,
+            &format!("/api/v3/stories/{}/cover", story_id),
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .requires_auth()
+            .fields::<StoryField>(None)?
+            .multipart(form)
+            .execute()
+            .await
+    }
+
+    /// Builds an EPUB 3 e-book of a story.
+    ///
+    /// The story's metadata and the content of all its parts are fetched (the parts
+    /// concurrently), then assembled into a book with a cover image (if the story has one),
+    /// a title page, a table of contents and one chapter per part.
+    ///
+    /// # Arguments
+    /// * `story_id` - The unique identifier of the story to export.
+    ///
+    /// # Returns
+    /// A `Result` containing the EPUB file as `Bytes` on success.
+    ///
+    /// # Errors
+    /// Returns a `WattpadError` if any request fails, including the cover download, or
+    /// `WattpadError::ExportFailed` if the archive cannot be written.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::WattpadClient;
+    /// # use std::fs::File;
+    /// # use std::io::Write;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = WattpadClient::new();
+    /// let story_id = 12345678;
+    ///
+    /// let epub = client.story.export_story_epub(story_id).await?;
+    ///
+    /// let mut file = File::create(format!("{}.epub", story_id))?;
+    /// file.write_all(&epub)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "epub")]
+    pub async fn export_story_epub(
+        &self,
+        story_id: impl Into<StoryId>,
+    ) -> Result<Bytes, WattpadError> {
+        let story_id = checked_story_id(story_id)?;
+
+        let fields = [
+            StoryField::Title,
+            StoryField::User(vec![UserStubField::Username]),
+            StoryField::Description,
+            StoryField::Cover,
+            StoryField::ModifyDate,
+            StoryField::Parts(vec![PartStubField::Id, PartStubField::Title]),
+        ];
+        let story = self.get_story_info(story_id, Some(&fields)).await?;
+        let parts = story.parts.unwrap_or_default();
+
+        let contents = join_all(parts.iter().map(|part| async move {
+            match part.id {
+                Some(id) => self.get_part_content_raw(id).await,
+                None => Err(WattpadError::MissingRequiredField {
+                    field: "id".to_string(),
+                    context: "Cannot export a part without an id.".to_string(),
+                }),
+            }
+        }))
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let chapters = parts
+            .into_iter()
+            .zip(contents)
+            .enumerate()
+            .map(|(index, (part, html))| epub::Chapter {
+                title: part.title.unwrap_or_else(|| format!("Part {}", index + 1)),
+                html,
+            })
+            .collect::<Vec<_>>();
+
+        let cover = match story.cover.as_deref() {
+            Some(url) if !url.is_empty() => Some(self.download_cover(url).await?),
+            _ => None,
+        };
+
+        let metadata = epub::Metadata {
+            id: story_id.to_string(),
+            title: story.title.unwrap_or_else(|| format!("Story {}", story_id)),
+            author: story
+                .user
+                .and_then(|user| user.username)
+                .unwrap_or_else(|| "Unknown".to_string()),
+            description: story.description,
+            modified: story
+                .modify_date
+                .as_deref()
+                .and_then(|date| date.get(..19))
+                .map_or_else(|| "1970-01-01T00:00:00Z".to_string(), |date| format!("{}Z", date)),
+        };
+
+        epub::build(&metadata, cover.as_ref(), &chapters)
+    }
+
+    /// Downloads a cover image, keeping the media type reported by the server.
+    #[cfg(feature = "epub")]
+    async fn download_cover(&self, url: &str) -> Result<epub::Cover, WattpadError> {
+        let response = download(&self.http, url).await?;
+        let media_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.split(';').next())
+            .map_or_else(|| "image/jpeg".to_string(), |value| value.trim().to_string());
+        let data = response.bytes().await?;
+
+        Ok(epub::Cover { data, media_type })
+    }
+
+    /// Exports a whole story as a single plain-text document.
+    ///
+    /// The content of every part is fetched concurrently, stripped of HTML with
+    /// `PartContent::to_plain_text`, and joined in reading order. Each part is preceded by a
+    /// `--- {part title} ---` separator line.
+    ///
+    /// # Arguments
+    /// * `story_id` - The unique identifier of the story to export.
+    ///
+    /// # Returns
+    /// A `Result` containing the story text on success.
+    ///
+    /// # Errors
+    /// Returns a `WattpadError` as soon as any request fails; the remaining part fetches are
+    /// abandoned.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::WattpadClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = WattpadClient::new();
+    /// let story_id = 12345678;
+    ///
+    /// let text = client.story.export_story_text(story_id).await?;
+    /// std::fs::write(format!("{}.txt", story_id), text)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn export_story_text(
+        &self,
+        story_id: impl Into<StoryId>,
+    ) -> Result<String, WattpadError> {
+        let story_id = checked_story_id(story_id)?;
+
+        let fields = [StoryField::Parts(vec![PartStubField::Id, PartStubField::Title])];
+        let story = self.get_story_info(story_id, Some(&fields)).await?;
+        let parts = story.parts.unwrap_or_default();
+
+        let contents = try_join_all(parts.iter().map(|part| async move {
+            match part.id {
+                Some(id) => self.get_part_content_json(id).await,
+                None => Err(WattpadError::MissingRequiredField {
+                    field: "id".to_string(),
+                    context: "Cannot export a part without an id.".to_string(),
+                }),
+            }
+        }))
+            .await?;
+
+        let mut text = String::new();
+        for (index, (part, content)) in parts.into_iter().zip(contents).enumerate() {
+            let title = part.title.unwrap_or_else(|| format!("Part {}", index + 1));
+            text.push_str(&format!("\n\n--- {} ---\n\n", title));
+            text.push_str(&content.to_plain_text().unwrap_or_default());
+        }
+
+        Ok(text.trim_start_matches('\n').to_string())
+    }
+
+    /// Votes for a single story part as the authenticated user.
+    ///
+    /// # Arguments
+    /// * `part_id` - The unique identifier of the story part to vote for.
+    ///
+    /// # Returns
+    /// An empty `Ok(())` on success.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::AuthenticationRequired` if the client is not authenticated,
+    /// `WattpadError::AlreadyVoted` if the user has already voted for this part,
+    /// `WattpadError::StoryPartNotFound` if the part does not exist, or another `WattpadError`
+    /// if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::WattpadClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// client.authenticate("username", "password").await?;
+    ///
+    /// client.story.vote_part(87654321).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn vote_part(&self, part_id: impl Into<PartId>) -> Result<(), WattpadError> {
+        let part_id = checked_part_id(part_id)?;
+
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::POST,
+            &format!("/api/v3/story_parts/{}/votes", part_id),
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .requires_auth()
+            .execute_empty()
+            .await
+            .map_err(|e| match e {
+                WattpadError::HttpError { status: 409, .. } => WattpadError::AlreadyVoted,
+                e => part_not_found(e),
+            })
+    }
+
+    /// Removes the authenticated user's vote from a single story part.
+    ///
+    /// This is the counterpart to `vote_part`, and can be used together with it to
+    /// implement a toggleable vote.
+    ///
+    /// # Arguments
+    /// * `part_id` - The unique identifier of the story part to remove the vote from.
+    ///
+    /// # Returns
+    /// An empty `Ok(())` on success.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::AuthenticationRequired` if the client is not authenticated,
+    /// `WattpadError::NotVoted` if the user has not voted for this part, or another
+    /// `WattpadError` if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::WattpadClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// client.authenticate("username", "password").await?;
+    ///
+    /// client.story.unvote_part(87654321).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn unvote_part(&self, part_id: impl Into<PartId>) -> Result<(), WattpadError> {
+        let part_id = checked_part_id(part_id)?;
+
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::DELETE,
+            &format!("/api/v3/story_parts/{}/votes", part_id),
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .requires_auth()
+            .execute_empty()
+            .await
+            .map_err(|e| match e {
+                // There is no vote resource to delete.
+                WattpadError::HttpError { status: 404, .. } => WattpadError::NotVoted,
+                e => e,
+            })
+    }
+
+    /// Records that the authenticated user has finished reading a story part.
+    ///
+    /// This is what the official apps report at the end of a part, and counts towards the
+    /// part's read count. It is separate from the saved reading position; see
+    /// `set_reading_position`.
+    ///
+    /// # Arguments
+    /// * `part_id` - The unique identifier of the story part that was read.
+    ///
+    /// # Returns
+    /// An empty `Ok(())` on success.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::AuthenticationRequired` if the client is not authenticated,
+    /// `WattpadError::StoryPartNotFound` if the part does not exist, or another `WattpadError`
+    /// if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::WattpadClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// client.authenticate("username", "password").await?;
+    ///
+    /// client.story.mark_part_read(87654321).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn mark_part_read(&self, part_id: impl Into<PartId>) -> Result<(), WattpadError> {
+        let part_id = checked_part_id(part_id)?;
+
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::POST,
+            &format!("/api/v3/story_parts/{}/reads", part_id),
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .requires_auth()
+            .execute_empty()
+            .await
+            .map_err(part_not_found)
+    }
+
+    /// Reports a story to Wattpad's moderators as the authenticated user.
+    ///
+    /// # Arguments
+    /// * `story_id` - The unique identifier of the story to report.
+    /// * `reason` - Why the content is being reported.
+    ///
+    /// # Returns
+    /// An empty `Ok(())` once the report has been submitted.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::AuthenticationRequired` if the client is not authenticated, or
+    /// another `WattpadError` if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::WattpadClient;
+    /// # use wp_mini::types::ReportReason;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// client.authenticate("username", "password").await?;
+    ///
+    /// client.story.report_story(12345678, ReportReason::Spam).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn report_story(
+        &self,
+        story_id: impl Into<StoryId>,
+        reason: ReportReason,
+    ) -> Result<(), WattpadError> {
+        let story_id = checked_story_id(story_id)?;
+
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::POST,
+            &format!("/api/v3/stories/{}/reports", story_id),
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .requires_auth()
+            .json_body(&reason)
+            .execute_empty()
+            .await
+    }
+
+    /// Reports a single story part to Wattpad's moderators as the authenticated user.
+    ///
+    /// # Arguments
+    /// * `part_id` - The unique identifier of the story part to report.
+    /// * `reason` - Why the content is being reported.
+    ///
+    /// # Returns
+    /// An empty `Ok(())` once the report has been submitted.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::AuthenticationRequired` if the client is not authenticated,
+    /// `WattpadError::StoryPartNotFound` if the part does not exist, or another `WattpadError`
+    /// if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::WattpadClient;
+    /// # use wp_mini::types::ReportReason;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// client.authenticate("username", "password").await?;
+    ///
+    /// client.story.report_part(87654321, ReportReason::Spam).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn report_part(
+        &self,
+        part_id: impl Into<PartId>,
+        reason: ReportReason,
+    ) -> Result<(), WattpadError> {
+        let part_id = checked_part_id(part_id)?;
+
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::POST,
+            &format!("/api/v3/story_parts/{}/reports", part_id),
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .requires_auth()
+            .json_body(&reason)
+            .execute_empty()
+            .await
+            .map_err(part_not_found)
+    }
+
+    /// Returns stories similar to the given story, as recommended by Wattpad.
+    ///
+    /// Results are ordered by Wattpad's internal relevance score.
+    ///
+    /// # Arguments
+    /// * `story_id` - The unique identifier of the story to find similar stories for.
+    /// * `fields` - An optional slice of `StoryField` specifying which fields to retrieve.
+    ///   If `None`, a comprehensive list of all known fields will be requested by default.
+    /// * `pagination` - An optional `PaginationParams` giving the maximum number of stories to
+    ///   return and how many to skip.
+    ///
+    /// # Returns
+    /// A `Result` containing a `PaginatedResponse<StoryResponse>` on success. The page is empty
+    /// if Wattpad has no recommendations for the story (e.g., for new or obscure stories).
+    ///
+    /// # Errors
+    /// Returns a `WattpadError` if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::{WattpadClient, field::StoryField};
+    /// # use wp_mini::PaginationParams;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// let fields = &[StoryField::Id, StoryField::Title];
+    ///
+    /// let first_page = PaginationParams::new(10, 0);
+    /// let similar = client
+    ///     .story
+    ///     .get_similar_stories(12345678, Some(fields), Some(first_page))
+    ///     .await?;
+    ///
+    /// for story in similar {
+    ///     println!("You might also like: {:?}", story.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_similar_stories(
+        &self,
+        story_id: impl Into<StoryId>,
+        fields: Option<&[StoryField]>,
+        pagination: Option<PaginationParams>,
+    ) -> Result<PaginatedResponse<StoryResponse>, WattpadError> {
+        let story_id = checked_story_id(story_id)?;
+
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::GET,
+            &format!("/api/v3/stories/{}/similar", story_id),
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .collection("stories")
+            .fields(fields)?
+            .pagination(pagination)
+            .execute_paginated()
+            .await
+    }
+
+    /// Returns stories that Wattpad recommends for the authenticated user.
+    ///
+    /// Unlike `get_similar_stories`, which is based on a single story and needs no login,
+    /// these recommendations are personalized from the user's reading history.
+    ///
+    /// # Arguments
+    /// * `fields` - An optional slice of `StoryField` specifying which fields to retrieve.
+    ///   If `None`, a comprehensive list of all known fields will be requested by default.
+    /// * `pagination` - An optional `PaginationParams` giving the maximum number of stories to
+    ///   return and how many to skip.
+    ///
+    /// # Returns
+    /// A `Result` containing a `PaginatedResponse<StoryResponse>` on success. The page is empty
+    /// if the user has no reading history to base recommendations on yet.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::AuthenticationRequired` if the client is not authenticated, or
+    /// another `WattpadError` if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::{WattpadClient, field::StoryField};
+    /// # use wp_mini::PaginationParams;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// client.authenticate("username", "password").await?;
+    /// let fields = &[StoryField::Id, StoryField::Title];
+    ///
+    /// let first_page = PaginationParams::new(10, 0);
+    /// for story in client.story.get_recommended_for_user(Some(fields), Some(first_page)).await? {
+    ///     println!("Recommended: {:?}", story.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_recommended_for_user(
+        &self,
+        fields: Option<&[StoryField]>,
+        pagination: Option<PaginationParams>,
+    ) -> Result<PaginatedResponse<StoryResponse>, WattpadError> {
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::GET,
+            "/api/v3/users/me/recommended",
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .requires_auth()
+            .collection("stories")
+            .fields(fields)?
+            .pagination(pagination)
+            .execute_paginated()
+            .await
+    }
+
+    /// Returns the stories currently trending on Wattpad.
+    ///
+    /// # Arguments
+    /// * `fields` - An optional slice of `StoryField` specifying which fields to retrieve.
+    ///   If `None`, a comprehensive list of all known fields will be requested by default.
+    /// * `filter` - An optional `StoryFilter` restricting which stories are returned.
+    /// * `pagination` - An optional `PaginationParams` giving the maximum number of stories to
+    ///   return and how many to skip.
+    ///
+    /// # Returns
+    /// A `Result` containing a `PaginatedResponse<StoryResponse>` on success. The page is
+    /// empty if the `offset` is past the end of the trending list.
+    ///
+    /// # Errors
+    /// Returns a `WattpadError` if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::{WattpadClient, field::StoryField};
+    /// # use wp_mini::PaginationParams;
+    /// # use wp_mini::types::StoryFilter;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// let fields = &[StoryField::Title, StoryField::ReadCount];
+    ///
+    /// let completed = StoryFilter { completed: Some(true), ..Default::default() };
+    ///
+    /// let first_page = PaginationParams::new(20, 0);
+    /// let trending = client
+    ///     .story
+    ///     .get_trending_stories(Some(fields), Some(&completed), Some(first_page))
+    ///     .await?;
+    ///
+    /// for story in trending {
+    ///     println!("{:?} ({:?} reads)", story.title, story.read_count);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_trending_stories(
+        &self,
+        fields: Option<&[StoryField]>,
+        filter: Option<&StoryFilter>,
+        pagination: Option<PaginationParams>,
+    ) -> Result<PaginatedResponse<StoryResponse>, WattpadError> {
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::GET,
+            "/api/v3/stories",
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .collection("stories")
+            .fields(fields)?
+            .param("filter", Some("hot"))
+            .story_filter(filter.unwrap_or(&StoryFilter::default()))
+            .pagination(pagination)
+            .execute_paginated()
+            .await
+    }
+
+    /// Returns the stories currently featured by Wattpad's editors.
+    ///
+    /// This is the editorial "featured" selection shown on the Wattpad home page. No
+    /// authentication is required.
+    ///
+    /// # Arguments
+    /// * `fields` - An optional slice of `StoryField` specifying which fields to retrieve.
+    ///   If `None`, a comprehensive list of all known fields will be requested by default.
+    /// * `pagination` - An optional `PaginationParams` giving the maximum number of stories to
+    ///   return and how many to skip.
+    ///
+    /// # Returns
+    /// A `Result` containing a `PaginatedResponse<StoryResponse>` on success. The page is empty
+    /// if no stories are currently featured.
+    ///
+    /// # Errors
+    /// Returns a `WattpadError` if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::{WattpadClient, field::StoryField};
+    /// # use wp_mini::PaginationParams;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// let fields = &[StoryField::Id, StoryField::Title];
+    ///
+    /// let first_page = PaginationParams::new(10, 0);
+    /// for story in client.story.get_featured_stories(Some(fields), Some(first_page)).await? {
+    ///     println!("{:?}", story.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_featured_stories(
+        &self,
+        fields: Option<&[StoryField]>,
+        pagination: Option<PaginationParams>,
+    ) -> Result<PaginatedResponse<StoryResponse>, WattpadError> {
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::GET,
+            "/api/v3/stories",
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .collection("stories")
+            .fields(fields)?
+            .param("filter", Some("featured"))
+            .pagination(pagination)
+            .execute_paginated()
+            .await
+    }
+
+    /// Returns the stories in a specific category.
+    ///
+    /// # Arguments
+    /// * `category_id` - The numerical identifier of the category. Named constants for the
+    ///   known categories are available in [`crate::types::category_ids`].
+    /// * `fields` - An optional slice of `StoryField` specifying which fields to retrieve.
+    ///   If `None`, a comprehensive list of all known fields will be requested by default.
+    /// * `filter` - An optional `StoryFilter` restricting which stories are returned. Its
+    ///   `category` is ignored in favour of `category_id`.
+    /// * `pagination` - An optional `PaginationParams` giving the maximum number of stories to
+    ///   return and how many to skip.
+    ///
+    /// # Returns
+    /// A `Result` containing a `PaginatedResponse<StoryResponse>` on success.
+    ///
+    /// # Errors
+    /// Returns a `WattpadError` if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::{WattpadClient, field::StoryField};
+    /// # use wp_mini::types::category_ids;
+    /// # use wp_mini::PaginationParams;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// let fields = &[StoryField::Id, StoryField::Title];
+    ///
+    /// let first_page = PaginationParams::new(20, 0);
+    /// let stories = client
+    ///     .story
+    ///     .get_stories_by_category(category_ids::ROMANCE, Some(fields), None, Some(first_page))
+    ///     .await?;
+    ///
+    /// println!("Fetched {} of {:?} romance stories", stories.items.len(), stories.total);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_stories_by_category(
+        &self,
+        category_id: u32,
+        fields: Option<&[StoryField]>,
+        filter: Option<&StoryFilter>,
+        pagination: Option<PaginationParams>,
+    ) -> Result<PaginatedResponse<StoryResponse>, WattpadError> {
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::GET,
+            "/api/v3/stories",
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .collection("stories")
+            .fields(fields)?
+            .story_filter(&StoryFilter {
+                category: Some(category_id),
+                ..filter.copied().unwrap_or_default()
+            })
+            .pagination(pagination)
+            .execute_paginated()
+            .await
+    }
+
+    /// Returns the stories tagged with a specific tag.
+    ///
+    /// # Arguments
+    /// * `tag` - The tag to browse (e.g., `"werewolf"`). It is URL-encoded before being sent.
+    /// * `fields` - An optional slice of `StoryField` specifying which fields to retrieve.
+    ///   If `None`, a comprehensive list of all known fields will be requested by default.
+    /// * `pagination` - An optional `PaginationParams` giving the maximum number of stories to
+    ///   return and how many to skip.
+    ///
+    /// # Returns
+    /// A `Result` containing a `PaginatedResponse<StoryResponse>` on success. The page is
+    /// empty if no stories use the tag.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::InvalidInput` if `tag` is empty or only whitespace, or another
+    /// `WattpadError` if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::{WattpadClient, field::StoryField};
+    /// # use wp_mini::PaginationParams;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// let fields = &[StoryField::Id, StoryField::Title, StoryField::Tags];
+    ///
+    /// let first_page = PaginationParams::new(20, 0);
+    /// let stories = client.story.get_stories_by_tag("werewolf", Some(fields), Some(first_page)).await?;
+    ///
+    /// for story in stories {
+    ///     println!("{:?}: {:?}", story.title, story.tags);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_stories_by_tag(
+        &self,
+        tag: &str,
+        fields: Option<&[StoryField]>,
+        pagination: Option<PaginationParams>,
+    ) -> Result<PaginatedResponse<StoryResponse>, WattpadError> {
+        let tag = tag.trim();
+        if tag.is_empty() {
+            return Err(WattpadError::InvalidInput {
+                field: "tag".to_string(),
+                reason: "The tag must not be empty.".to_string(),
+            });
+        }
+
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::GET,
+            &format!(
+                "/api/v3/tags/{}/stories",
+                utf8_percent_encode(tag, NON_ALPHANUMERIC)
+            ),
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .collection("stories")
+            .fields(fields)?
+            .pagination(pagination)
+            .execute_paginated()
+            .await
+    }
+
+    /// Returns the announcements the author has posted on a story.
+    ///
+    /// # Arguments
+    /// * `story_id` - The unique identifier of the story.
+    /// * `fields` - An optional slice of `AnnouncementField` specifying which fields to retrieve.
+    ///   If `None`, a default set of fields will be requested.
+    /// * `pagination` - An optional `PaginationParams` giving the maximum number of
+    ///   announcements to return and how many to skip.
+    ///
+    /// # Returns
+    /// A `Result` containing a `PaginatedResponse<AnnouncementResponse>` on success. The page
+    /// is empty if the story has no announcements.
+    ///
+    /// # Errors
+    /// Returns a `WattpadError` if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::WattpadClient;
+    /// # use wp_mini::PaginationParams;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    ///
+    /// let first_page = PaginationParams::new(5, 0);
+    /// let announcements = client.story.get_story_announcements(12345678, None, Some(first_page)).await?;
+    ///
+    /// for announcement in announcements {
+    ///     println!("{:?}: {:?}", announcement.create_date, announcement.message);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_story_announcements(
+        &self,
+        story_id: impl Into<StoryId>,
+        fields: Option<&[AnnouncementField]>,
+        pagination: Option<PaginationParams>,
+    ) -> Result<PaginatedResponse<AnnouncementResponse>, WattpadError> {
+        let story_id = checked_story_id(story_id)?;
+
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::GET,
+            &format!("/api/v3/stories/{}/announcements", story_id),
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .collection("announcements")
+            .fields(fields)?
+            .pagination(pagination)
+            .execute_paginated()
+            .await
+    }
+
+    /// Returns a single page of a story's parts, as lightweight part stubs.
+    ///
+    /// Requesting `StoryField::Parts` through `get_story_info` returns every part at once;
+    /// this endpoint lets long stories be fetched a page at a time instead.
+    ///
+    /// # Arguments
+    /// * `story_id` - The unique identifier of the story.
+    /// * `fields` - An optional slice of `PartStubField` specifying which fields to retrieve.
+    ///   If `None`, a default set of fields will be requested.
+    /// * `pagination` - An optional `PaginationParams` giving the maximum number of parts to
+    ///   return and how many to skip.
+    ///
+    /// # Returns
+    /// A `Result` containing a `PaginatedResponse<PartStubResponse>` on success, with the
+    /// parts in reading order.
+    ///
+    /// # Errors
+    /// Returns a `WattpadError` if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::{WattpadClient, field::PartStubField};
+    /// # use wp_mini::PaginationParams;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// let fields = &[PartStubField::Id, PartStubField::Title];
+    ///
+    /// let first_page = PaginationParams::new(50, 0);
+    /// let page = client
+    ///     .story
+    ///     .get_story_parts_paginated(12345678, Some(fields), Some(first_page))
+    ///     .await?;
+    ///
+    /// for part in &page.items {
+    ///     println!("{:?}", part.title);
+    /// }
+    /// if let Some(next) = page.next_offset {
+    ///     println!("More parts start at offset {}", next);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_story_parts_paginated(
+        &self,
+        story_id: impl Into<StoryId>,
+        fields: Option<&[PartStubField]>,
+        pagination: Option<PaginationParams>,
+    ) -> Result<PaginatedResponse<PartStubResponse>, WattpadError> {
+        let story_id = checked_story_id(story_id)?;
+
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::GET,
+            &format!("/api/v3/stories/{}/parts", story_id),
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .collection("parts")
+            .fields(fields)?
+            .pagination(pagination)
+            .execute_paginated()
+            .await
+    }
+
+    /// Returns a page of a story's parts, ordered by the given part attribute.
+    ///
+    /// This lets the API do the sorting, e.g. to find a story's most-read part without
+    /// fetching every part first.
+    ///
+    /// # Arguments
+    /// * `story_id` - The unique identifier of the story.
+    /// * `sort_by` - The part attribute to order by.
+    /// * `order` - Whether to sort in ascending or descending order.
+    /// * `pagination` - An optional `PaginationParams` giving the maximum number of parts to
+    ///   return and how many to skip.
+    ///
+    /// # Returns
+    /// A `Result` containing a `PaginatedResponse<PartStubResponse>` on success, with the
+    /// parts in the requested order.
+    ///
+    /// # Errors
+    /// Returns a `WattpadError` if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::{PaginationParams, WattpadClient};
+    /// # use wp_mini::types::{PartSortField, SortOrder};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    ///
+    /// let top = client
+    ///     .story
+    ///     .get_story_parts_sorted(
+    ///         12345678,
+    ///         PartSortField::ReadCount,
+    ///         SortOrder::Descending,
+    ///         Some(PaginationParams::new(1, 0)),
+    ///     )
+    ///     .await?;
+    ///
+    /// if let Some(part) = top.items.first() {
+    ///     println!("Most read: {:?} ({:?} reads)", part.title, part.read_count);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_story_parts_sorted(
+        &self,
+        story_id: impl Into<StoryId>,
+        sort_by: PartSortField,
+        order: SortOrder,
+        pagination: Option<PaginationParams>,
+    ) -> Result<PaginatedResponse<PartStubResponse>, WattpadError> {
+        let story_id = checked_story_id(story_id)?;
+
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::GET,
+            &format!("/api/v3/stories/{}/parts", story_id),
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .collection("parts")
+            .fields::<PartStubField>(None)?
+            .sort(Some((sort_by.code(), order)))
+            .pagination(pagination)
+            .execute_paginated()
+            .await
+    }
+
+    /// Returns the authenticated user's reading position in a story.
+    ///
+    /// # Arguments
+    /// * `story_id` - The unique identifier of the story.
+    ///
+    /// # Returns
+    /// A `Result` containing a `ReadingPositionResponse` with the last part read and the
+    /// position within it on success.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::AuthenticationRequired` if the client is not authenticated, or
+    /// another `WattpadError` if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::WattpadClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// client.authenticate("username", "password").await?;
+    ///
+    /// let position = client.story.get_reading_position(12345678).await?;
+    /// println!("Resume part {:?} at {:?}", position.part_id, position.position);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_reading_position(
+        &self,
+        story_id: impl Into<StoryId>,
+    ) -> Result<ReadingPositionResponse, WattpadError> {
+        let story_id = checked_story_id(story_id)?;
+
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::GET,
+            &format!("/api/v3/stories/{}/reading_position", story_id),
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .requires_auth()
+            .execute()
+            .await
+    }
+
+    /// Saves the authenticated user's reading position in a story.
+    ///
+    /// # Arguments
+    /// * `story_id` - The unique identifier of the story.
+    /// * `part_id` - The unique identifier of the part being read.
+    /// * `position` - The offset within the part where the user stopped reading.
+    ///
+    /// # Returns
+    /// An empty `Ok(())` on success.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::AuthenticationRequired` if the client is not authenticated, or
+    /// another `WattpadError` if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::WattpadClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// client.authenticate("username", "password").await?;
+    ///
+    /// client.story.set_reading_position(12345678, 87654321, 1200).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_reading_position(
+        &self,
+        story_id: impl Into<StoryId>,
+        part_id: impl Into<PartId>,
+        position: u64,
+    ) -> Result<(), WattpadError> {
+        let story_id = checked_story_id(story_id)?;
+        let part_id = checked_part_id(part_id)?;
+
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::PUT,
+            &format!("/api/v3/stories/{}/reading_position", story_id),
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .requires_auth()
+            .json_body(&ReadingPositionUpdate { part_id, position })
+            .execute_empty()
+            .await
+    }
+
+    /// Returns the per-part engagement statistics of a story owned by the authenticated user.
+    ///
+    /// # Arguments
+    /// * `story_id` - The unique identifier of the story.
+    ///
+    /// # Returns
+    /// A `Result` containing a `WritingStatsResponse` with the reads, votes, comments and daily
+    /// reads of each part on success.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::AuthenticationRequired` if the client is not authenticated,
+    /// `WattpadError::AccessDenied` if the authenticated user is not the story's author, or
+    /// another `WattpadError` if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::WattpadClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// client.authenticate("username", "password").await?;
+    ///
+    /// let stats = client.story.get_story_writing_stats(12345678).await?;
+    ///
+    /// for part in stats.part_stats.unwrap_or_default() {
+    ///     println!("Part {:?}: {:?} reads", part.part_id, part.reads);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_story_writing_stats(
+        &self,
+        story_id: impl Into<StoryId>,
+    ) -> Result<WritingStatsResponse, WattpadError> {
+        let story_id = checked_story_id(story_id)?;
+
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::GET,
+            &format!("/api/v3/stories/{}/stats", story_id),
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .requires_auth()
+            .execute()
+            .await
+            .map_err(|e| match e {
+                // Non-authors may get a bare 403 rather than an API error code.
+                WattpadError::HttpError { status: 403, .. } => WattpadError::AccessDenied,
+                e => e,
+            })
+    }
+
+    /// Returns a `Stream` over every part of a story, as lightweight part stubs.
+    ///
+    /// Parts are fetched lazily, one page at a time, so very long stories can be processed
+    /// part by part without loading all of their metadata into memory at once.
+    /// Requires the `stream` feature.
+    ///
+    /// # Arguments
+    /// * `story_id` - The unique identifier of the story.
+    /// * `fields` - An optional slice of `PartStubField` specifying which fields to retrieve.
+    ///   If `None`, a default set of fields will be requested.
+    ///
+    /// # Returns
+    /// A `PaginationStream` yielding a `Result<PartStubResponse, WattpadError>` per part.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use futures_util::StreamExt;
+    /// # use wp_mini::WattpadClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    ///
+    /// let mut parts = client.story.stream_story_parts(12345678, None);
+    /// while let Some(part) = parts.next().await {
+    ///     println!("{:?}", part?.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "stream")]
+    pub fn stream_story_parts<'a>(
+        &'a self,
+        story_id: impl Into<StoryId>,
+        fields: Option<&'a [PartStubField]>,
+    ) -> PaginationStream<'a, PartStubResponse> {
+        let story_id = story_id.into();
+
+        PaginationStream::new(STORY_PARTS_PAGE_SIZE, move |limit, offset| {
+            let page = PaginationParams::new(limit, offset);
+            Box::pin(self.get_story_parts_paginated(story_id, fields, Some(page)))
+        })
+    }
 }
\ No newline at end of file
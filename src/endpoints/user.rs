@@ -1,19 +1,20 @@
-use crate::client::WattpadRequestBuilder;
-use crate::field::UserField;
-use crate::types::UserResponse;
-use crate::WattpadError;
-use std::sync::atomic::AtomicBool;
-use std::sync::Arc;
-
-/// Provides access to user-related API endpoints.
-///
-/// This client allows you to fetch public information about Wattpad users.
-pub struct UserClient {
-    /// The shared `reqwest` client for making HTTP requests.
-    pub(crate) http: reqwest::Client,
-    /// A flag indicating whether the main client is authenticated.
-    pub(crate) is_authenticated: Arc<AtomicBool>,
-}
+use crate::client::{download, image_form, WattpadRequestBuilder};
+use crate::field::{MessageField, NotificationField, ReadingListField, StoryField, UserField};
+use crate::types::{
+    MessageResponse, NotificationResponse, ReadingListResponse, ReportReason, SortOrder,
+    StoryFilter, StoryResponse, StorySortField, UpdateProfileRequest, UserResponse, Username,
+};
+use crate::{PaginatedResponse, PaginationParams, WattpadError};
+use bytes::Bytes;
+#[cfg(feature = "stream")]
+use crate::PaginationStream;
+
+define_sub_client!(
+    /// Provides access to user-related API endpoints.
+    ///
+    /// This client allows you to fetch public information about Wattpad users.
+    UserClient, "user"
+);
 
 impl UserClient {
     /// Fetches detailed public information about a specific user.
@@ -22,7 +23,8 @@ impl UserClient {
     /// stories they've written, and more.
     ///
     /// # Arguments
-    /// * `username` - The username of the user to fetch.
+    /// * `username` - The username of the user to fetch, as a `Username` or as a `&str` or
+    ///   `String` that is validated the same way.
     /// * `fields` - An optional slice of `UserField` specifying which fields to retrieve.
     ///   If `None`, a default set of fields will be requested.
     ///
@@ -30,39 +32,668 @@ impl UserClient {
     /// A `Result` containing a `UserResponse` struct with the user's data on success.
     ///
     /// # Errors
-    /// Returns a `WattpadError` if the network request fails, the API returns an error
+    /// Returns `WattpadError::InvalidInput` if `username` is not a valid username, or another
+    /// `WattpadError` if the network request fails, the API returns an error
     /// (e.g., user not found), or a requested field requires authentication when the
     /// client is unauthenticated.
     ///
     /// # Examples
     /// ```no_run
-    /// # use wattpad::{WattpadClient, field::UserField};
+    /// # use wp_mini::{WattpadClient, field::UserField};
     /// # #[tokio::main]
-    /// # async fn main() -> Result<(), wattpad::WattpadError> {
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
     /// let client = WattpadClient::new();
     /// let username = "test";
-    /// let fields = &[UserField::Username, UserField::FollowerCount];
+    /// let fields = &[UserField::Username, UserField::NumFollowers];
     ///
     /// let user_info = client.user.get_user_info(username, Some(fields)).await?;
     ///
-    /// println!("User: {}", user_info.username);
-    /// println!("Followers: {}", user_info.follower_count);
+    /// println!("User: {:?}", user_info.username);
+    /// println!("Followers: {:?}", user_info.num_followers);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_user_info(
+    pub async fn get_user_info<U>(
         &self,
-        username: &str,
+        username: U,
         fields: Option<&[UserField]>,
-    ) -> Result<UserResponse, WattpadError> {
+    ) -> Result<UserResponse, WattpadError>
+    where
+        U: TryInto<Username>,
+        WattpadError: From<U::Error>,
+    {
+        let username = username.try_into()?;
         WattpadRequestBuilder::new(
             &self.http,
             &self.is_authenticated,
+            &self.base_url,
             reqwest::Method::GET,
             &format!("/api/v3/users/{}", username),
         )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .fields(fields)?
+            .execute()
+            .await
+    }
+
+    /// Downloads a user's profile picture (avatar).
+    ///
+    /// The avatar URL is looked up with `get_user_info`, then the image is fetched from
+    /// Wattpad's image CDN. The bytes are returned as served, whatever the image format.
+    ///
+    /// # Arguments
+    /// * `username` - The username of the user.
+    ///
+    /// # Returns
+    /// A `Result` containing the image as `Bytes` on success.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::MissingRequiredField` if the user has no avatar URL, or another
+    /// `WattpadError` if either request fails.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::WattpadClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = WattpadClient::new();
+    ///
+    /// let avatar = client.user.download_avatar("some_user").await?;
+    /// std::fs::write("some_user.png", &avatar)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_avatar(&self, username: &str) -> Result<Bytes, WattpadError> {
+        let user = self.get_user_info(username, Some(&[UserField::Avatar])).await?;
+        let url = user
+            .avatar
+            .filter(|url| !url.is_empty())
+            .ok_or_else(|| WattpadError::MissingRequiredField {
+                field: "avatar".to_string(),
+                context: "Cannot download an avatar without an avatar URL.".to_string(),
+            })?;
+
+        Ok(download(&self.http, &url).await?.bytes().await?)
+    }
+
+    /// Fetches the public reading lists created by a specific user.
+    ///
+    /// # Arguments
+    /// * `username` - The username of the user whose reading lists to fetch, as a `Username` or as
+    ///   a string that is validated the same way.
+    /// * `fields` - An optional slice of `ReadingListField` specifying which fields to retrieve.
+    ///   If `None`, a default set of fields will be requested.
+    /// * `pagination` - An optional `PaginationParams` giving the maximum number of reading
+    ///   lists to return and how many to skip.
+    ///
+    /// # Returns
+    /// A `Result` containing a `PaginatedResponse<ReadingListResponse>` on success.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::InvalidInput` if `username` is not a valid username, or another
+    /// `WattpadError` if the network request fails or the API returns an error (e.g., user not
+    /// found).
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::{WattpadClient, field::ReadingListField};
+    /// # use wp_mini::PaginationParams;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// let fields = &[ReadingListField::Name, ReadingListField::StoryCount];
+    ///
+    /// let first_page = PaginationParams::new(10, 0);
+    /// let lists = client
+    ///     .user
+    ///     .get_user_reading_lists("test", Some(fields), Some(first_page))
+    ///     .await?;
+    ///
+    /// for list in lists {
+    ///     println!("{:?} ({:?} stories)", list.name, list.story_count);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_user_reading_lists<U>(
+        &self,
+        username: U,
+        fields: Option<&[ReadingListField]>,
+        pagination: Option<PaginationParams>,
+    ) -> Result<PaginatedResponse<ReadingListResponse>, WattpadError>
+    where
+        U: TryInto<Username>,
+        WattpadError: From<U::Error>,
+    {
+        let username = username.try_into()?;
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::GET,
+            &format!("/api/v3/users/{}/lists", username),
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .collection("lists")
+            .fields(fields)?
+            .pagination(pagination)
+            .execute_paginated()
+            .await
+    }
+
+    /// Fetches the stories published by a specific user.
+    ///
+    /// # Arguments
+    /// * `username` - The username of the author, as a `Username` or as a string that is validated
+    ///   the same way.
+    /// * `fields` - An optional slice of `StoryField` specifying which fields to retrieve.
+    ///   If `None`, a comprehensive list of all known fields will be requested by default.
+    /// * `filter` - An optional `StoryFilter` restricting which stories are returned.
+    /// * `sort_by` - An optional field and direction to order the stories by. If `None`, the
+    ///   API's default order is used.
+    /// * `pagination` - An optional `PaginationParams` giving the maximum number of stories to
+    ///   return and how many to skip.
+    ///
+    /// # Returns
+    /// A `Result` containing a `PaginatedResponse<StoryResponse>` on success.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::InvalidInput` if `username` is not a valid username, or another
+    /// `WattpadError` if the network request fails or the API returns an error (e.g., user not
+    /// found).
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::{WattpadClient, field::StoryField};
+    /// # use wp_mini::PaginationParams;
+    /// # use wp_mini::types::{SortOrder, StorySortField};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// let fields = &[StoryField::Id, StoryField::Title];
+    /// let newest_first = (StorySortField::CreateDate, SortOrder::Descending);
+    ///
+    /// let first_page = PaginationParams::new(20, 0);
+    /// let page = client
+    ///     .user
+    ///     .get_user_stories("test", Some(fields), None, Some(newest_first), Some(first_page))
+    ///     .await?;
+    ///
+    /// println!("Showing {} of {:?} stories", page.items.len(), page.total);
+    /// if page.has_more() {
+    ///     println!("The next page starts at offset {:?}", page.next_offset);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_user_stories<U>(
+        &self,
+        username: U,
+        fields: Option<&[StoryField]>,
+        filter: Option<&StoryFilter>,
+        sort_by: Option<(StorySortField, SortOrder)>,
+        pagination: Option<PaginationParams>,
+    ) -> Result<PaginatedResponse<StoryResponse>, WattpadError>
+    where
+        U: TryInto<Username>,
+        WattpadError: From<U::Error>,
+    {
+        let username = username.try_into()?;
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::GET,
+            &format!("/api/v3/users/{}/stories", username),
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .collection("stories")
+            .fields(fields)?
+            .story_filter(filter.unwrap_or(&StoryFilter::default()))
+            .sort(sort_by.map(|(field, order)| (field.code(), order)))
+            .pagination(pagination)
+            .execute_paginated()
+            .await
+    }
+
+    /// Returns a `Stream` over every story published by a specific user.
+    ///
+    /// Stories are fetched lazily with `get_user_stories`, one page of `page_size` items at
+    /// a time. Requires the `stream` feature.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use futures_util::StreamExt;
+    /// # use wp_mini::WattpadClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    ///
+    /// let mut stories = client.user.stream_user_stories("test", None, 50);
+    /// while let Some(story) = stories.next().await {
+    ///     println!("{:?}", story?.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "stream")]
+    pub fn stream_user_stories<'a>(
+        &'a self,
+        username: &'a str,
+        fields: Option<&'a [StoryField]>,
+        page_size: u32,
+    ) -> PaginationStream<'a, StoryResponse> {
+        PaginationStream::new(page_size, move |limit, offset| {
+            let page = PaginationParams::new(limit, offset);
+            Box::pin(self.get_user_stories(username, fields, None, None, Some(page)))
+        })
+    }
+
+    /// Fetches the users following a specific user.
+    ///
+    /// # Arguments
+    /// * `username` - The username of the user whose followers to fetch, as a `Username` or as a
+    ///   string that is validated the same way.
+    /// * `fields` - An optional slice of `UserField` specifying which fields to retrieve.
+    ///   If `None`, a default set of fields will be requested.
+    /// * `pagination` - An optional `PaginationParams` giving the maximum number of followers to
+    ///   return and how many to skip.
+    ///
+    /// # Returns
+    /// A `Result` containing a `PaginatedResponse<UserResponse>` on success.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::InvalidInput` if `username` is not a valid username, or another
+    /// `WattpadError` if the network request fails, the API returns an error (e.g., user not
+    /// found), or a requested field requires authentication when the client is unauthenticated.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::{WattpadClient, field::UserField};
+    /// # use wp_mini::PaginationParams;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// let fields = &[UserField::Username];
+    ///
+    /// let first_page = PaginationParams::new(50, 0);
+    /// let followers = client.user.get_user_followers("test", Some(fields), Some(first_page)).await?;
+    ///
+    /// for follower in followers {
+    ///     println!("Follower: {:?}", follower.username);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_user_followers<U>(
+        &self,
+        username: U,
+        fields: Option<&[UserField]>,
+        pagination: Option<PaginationParams>,
+    ) -> Result<PaginatedResponse<UserResponse>, WattpadError>
+    where
+        U: TryInto<Username>,
+        WattpadError: From<U::Error>,
+    {
+        let username = username.try_into()?;
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::GET,
+            &format!("/api/v3/users/{}/followers", username),
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .collection("users")
+            .fields(fields)?
+            .pagination(pagination)
+            .execute_paginated()
+            .await
+    }
+
+    /// Fetches the private messages in the authenticated user's inbox.
+    ///
+    /// # Arguments
+    /// * `fields` - An optional slice of `MessageField` specifying which fields to retrieve.
+    ///   If `None`, a default set of fields will be requested.
+    /// * `pagination` - An optional `PaginationParams` giving the maximum number of messages to
+    ///   return and how many to skip.
+    ///
+    /// # Returns
+    /// A `Result` containing a `PaginatedResponse<MessageResponse>` on success, newest message
+    /// first.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::AuthenticationRequired` if the client is not authenticated, or
+    /// another `WattpadError` if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::{WattpadClient, field::{MessageField, UserStubField}};
+    /// # use wp_mini::PaginationParams;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// client.authenticate("username", "password").await?;
+    /// let fields = &[MessageField::Sender(vec![UserStubField::Username]), MessageField::Body];
+    ///
+    /// let first_page = PaginationParams::new(20, 0);
+    /// let messages = client.user.get_user_inbox(Some(fields), Some(first_page)).await?;
+    ///
+    /// for message in messages {
+    ///     println!("{:?}: {:?}", message.sender.and_then(|sender| sender.username), message.body);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_user_inbox(
+        &self,
+        fields: Option<&[MessageField]>,
+        pagination: Option<PaginationParams>,
+    ) -> Result<PaginatedResponse<MessageResponse>, WattpadError> {
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::GET,
+            "/api/v3/inbox",
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .requires_auth()
+            .collection("messages")
+            .fields(fields)?
+            .pagination(pagination)
+            .execute_paginated()
+            .await
+    }
+
+    /// Fetches the authenticated user's notifications.
+    ///
+    /// # Arguments
+    /// * `fields` - An optional slice of `NotificationField` specifying which fields to retrieve.
+    ///   If `None`, a default set of fields will be requested.
+    /// * `unread_only` - If `true`, only notifications that have not been read are returned.
+    /// * `pagination` - An optional `PaginationParams` giving the maximum number of
+    ///   notifications to return and how many to skip.
+    ///
+    /// # Returns
+    /// A `Result` containing a `PaginatedResponse<NotificationResponse>` on success, newest
+    /// first.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::AuthenticationRequired` if the client is not authenticated, or
+    /// another `WattpadError` if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::{WattpadClient, field::NotificationField};
+    /// # use wp_mini::PaginationParams;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// client.authenticate("username", "password").await?;
+    /// let fields = &[NotificationField::Type, NotificationField::Message];
+    ///
+    /// let first_page = PaginationParams::new(50, 0);
+    /// let unread = client
+    ///     .user
+    ///     .get_user_notifications(Some(fields), true, Some(first_page))
+    ///     .await?;
+    /// println!("You have {:?} unread notifications", unread.total);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_user_notifications(
+        &self,
+        fields: Option<&[NotificationField]>,
+        unread_only: bool,
+        pagination: Option<PaginationParams>,
+    ) -> Result<PaginatedResponse<NotificationResponse>, WattpadError> {
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::GET,
+            "/api/v3/notifications",
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .requires_auth()
+            .collection("notifications")
             .fields(fields)?
+            .param("unread", unread_only.then_some(true))
+            .pagination(pagination)
+            .execute_paginated()
+            .await
+    }
+
+    /// Updates the authenticated user's profile.
+    ///
+    /// Only the fields set on `update` are changed.
+    ///
+    /// # Arguments
+    /// * `update` - The changes to apply, built with `UpdateProfileRequest`.
+    ///
+    /// # Returns
+    /// A `Result` containing the updated `UserResponse` on success.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::InvalidInput` if a field exceeds Wattpad's length limit,
+    /// `WattpadError::AuthenticationRequired` if the client is not authenticated, or another
+    /// `WattpadError` if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::WattpadClient;
+    /// # use wp_mini::types::UpdateProfileRequest;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// client.authenticate("username", "password").await?;
+    ///
+    /// let update = UpdateProfileRequest::new().status("On a writing break");
+    /// let user = client.user.update_profile(update).await?;
+    /// println!("Updated profile for {:?}", user.username);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_profile(
+        &self,
+        update: UpdateProfileRequest,
+    ) -> Result<UserResponse, WattpadError> {
+        update.validate()?;
+
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::PATCH,
+            "/api/v3/users/me",
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .requires_auth()
+            .fields::<UserField>(None)?
+            .json_body(&update)
+            .execute()
+            .await
+    }
+
+    /// Replaces the authenticated user's profile picture (avatar).
+    ///
+    /// # Arguments
+    /// * `image_bytes` - The raw image data.
+    /// * `content_type` - The media type of the image: `image/jpeg`, `image/png` or
+    ///   `image/gif`.
+    ///
+    /// # Returns
+    /// A `Result` containing the updated `UserResponse`, with the new `avatar` URL, on success.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::InvalidInput` if `content_type` is not a supported image type,
+    /// `WattpadError::AuthenticationRequired` if the client is not authenticated, or another
+    /// `WattpadError` if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bytes::Bytes;
+    /// # use wp_mini::WattpadClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// client.authenticate("username", "password").await?;
+    ///
+    /// let image = Bytes::from(std::fs::read("avatar.png").unwrap());
+    /// let user = client.user.upload_avatar(image, "image/png").await?;
+    /// println!("New avatar: {:?}", user.avatar);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn upload_avatar(
+        &self,
+        image_bytes: Bytes,
+        content_type: &str,
+    ) -> Result<UserResponse, WattpadError> {
+        let form = image_form(image_bytes, content_type, "avatar")?;
+
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::POST,
+            "/api/v3/users/me/avatar",
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .requires_auth()
+            .fields::<UserField>(None)?
+            .multipart(form)
             .execute()
             .await
     }
+
+    /// Fetches the stories saved in the authenticated user's library.
+    ///
+    /// # Arguments
+    /// * `fields` - An optional slice of `StoryField` specifying which fields to retrieve.
+    ///   If `None`, a comprehensive list of all known fields will be requested by default.
+    /// * `pagination` - An optional `PaginationParams` giving the maximum number of stories to
+    ///   return and how many to skip.
+    ///
+    /// # Returns
+    /// A `Result` containing a `PaginatedResponse<StoryResponse>` on success, with the most
+    /// recently added stories first.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::AuthenticationRequired` if the client is not authenticated, or
+    /// another `WattpadError` if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::{WattpadClient, field::StoryField};
+    /// # use wp_mini::PaginationParams;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// client.authenticate("username", "password").await?;
+    /// let fields = &[StoryField::Id, StoryField::Title];
+    ///
+    /// let first_page = PaginationParams::new(20, 0);
+    /// let library = client.user.get_user_library(Some(fields), Some(first_page)).await?;
+    ///
+    /// for story in library {
+    ///     println!("Saved: {:?}", story.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_user_library(
+        &self,
+        fields: Option<&[StoryField]>,
+        pagination: Option<PaginationParams>,
+    ) -> Result<PaginatedResponse<StoryResponse>, WattpadError> {
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::GET,
+            "/api/v3/users/me/library",
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .requires_auth()
+            .collection("stories")
+            .fields(fields)?
+            .pagination(pagination)
+            .execute_paginated()
+            .await
+    }
+
+    /// Reports a user to Wattpad's moderators as the authenticated user.
+    ///
+    /// # Arguments
+    /// * `username` - The username of the user to report, as a `Username` or as a string that is
+    ///   validated the same way.
+    /// * `reason` - Why the content is being reported.
+    ///
+    /// # Returns
+    /// An empty `Ok(())` once the report has been submitted.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::InvalidInput` if `username` is not a valid username,
+    /// `WattpadError::AuthenticationRequired` if the client is not authenticated, or another
+    /// `WattpadError` if the network request fails or the API returns an error.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::WattpadClient;
+    /// # use wp_mini::types::ReportReason;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// client.authenticate("username", "password").await?;
+    ///
+    /// client.user.report_user("some_user", ReportReason::Spam).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn report_user<U>(
+        &self,
+        username: U,
+        reason: ReportReason,
+    ) -> Result<(), WattpadError>
+    where
+        U: TryInto<Username>,
+        WattpadError: From<U::Error>,
+    {
+        let username = username.try_into()?;
+        WattpadRequestBuilder::new(
+            &self.http,
+            &self.is_authenticated,
+            &self.base_url,
+            reqwest::Method::POST,
+            &format!("/api/v3/users/{}/reports", username),
+        )
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .cache(&self.cache)
+            .requires_auth()
+            .json_body(&reason)
+            .execute_empty()
+            .await
+    }
 }
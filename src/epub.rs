@@ -0,0 +1,218 @@
+//! Assembles EPUB 3 archives for `StoryClient::export_story_epub`.
+//!
+//! The archive is built in memory with every entry stored uncompressed, which keeps the
+//! dependency footprint small and satisfies the EPUB requirement that `mimetype` is stored.
+
+use crate::error::WattpadError;
+use crate::html::{escape_xml, to_xhtml};
+use bytes::Bytes;
+use std::io::{Cursor, Write};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+/// A chapter of the book: the part title and its raw HTML content.
+pub(crate) struct Chapter {
+    pub(crate) title: String,
+    pub(crate) html: String,
+}
+
+/// A cover image and its media type (e.g., `image/jpeg`).
+pub(crate) struct Cover {
+    pub(crate) data: Bytes,
+    pub(crate) media_type: String,
+}
+
+/// The book-level metadata written to the package document.
+pub(crate) struct Metadata {
+    pub(crate) id: String,
+    pub(crate) title: String,
+    pub(crate) author: String,
+    pub(crate) description: Option<String>,
+    /// The last modification time in `CCYY-MM-DDThh:mm:ssZ` form.
+    pub(crate) modified: String,
+}
+
+const CONTAINER_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<container version="1.0" xmlns="urn:oasis:names:tc:opendocument:xmlns:container">
+  <rootfiles>
+    <rootfile full-path="OEBPS/content.opf" media-type="application/oebps-package+xml"/>
+  </rootfiles>
+</container>
+"#;
+
+/// Builds a complete EPUB file from the story metadata, optional cover and chapters.
+pub(crate) fn build(
+    metadata: &Metadata,
+    cover: Option<&Cover>,
+    chapters: &[Chapter],
+) -> Result<Bytes, WattpadError> {
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Stored);
+
+    let mut add = |name: &str, data: &[u8]| -> Result<(), WattpadError> {
+        zip.start_file(name, options).map_err(export_error)?;
+        zip.write_all(data).map_err(export_error)
+    };
+
+    // The `mimetype` entry must come first.
+    add("mimetype", b"application/epub+zip")?;
+    add("META-INF/container.xml", CONTAINER_XML.as_bytes())?;
+
+    let cover_file = cover.map(|cover| format!("cover.{}", extension(&cover.media_type)));
+    if let (Some(cover), Some(file)) = (cover, &cover_file) {
+        add(&format!("OEBPS/{}", file), &cover.data)?;
+    }
+
+    add("OEBPS/title.xhtml", title_page(metadata, cover_file.as_deref()).as_bytes())?;
+    for (index, chapter) in chapters.iter().enumerate() {
+        let page = xhtml_page(&chapter.title, &format!(
+            "<h1>{}</h1>\n{}",
+            escape_xml(&chapter.title),
+            to_xhtml(&chapter.html)
+        ));
+        add(&format!("OEBPS/chapter-{}.xhtml", index + 1), page.as_bytes())?;
+    }
+    add("OEBPS/nav.xhtml", nav_page(chapters).as_bytes())?;
+    add(
+        "OEBPS/content.opf",
+        package_document(metadata, cover.zip(cover_file.as_deref()), chapters.len()).as_bytes(),
+    )?;
+
+    let cursor = zip.finish().map_err(export_error)?;
+    Ok(Bytes::from(cursor.into_inner()))
+}
+
+/// Wraps a ZIP or I/O failure in a `WattpadError`.
+fn export_error(e: impl std::fmt::Display) -> WattpadError {
+    WattpadError::ExportFailed {
+        format: "EPUB",
+        reason: e.to_string(),
+    }
+}
+
+/// Picks a file extension for an image media type.
+fn extension(media_type: &str) -> &'static str {
+    match media_type {
+        "image/png" => "png",
+        "image/gif" => "gif",
+        "image/webp" => "webp",
+        _ => "jpg",
+    }
+}
+
+/// Wraps body content in a minimal XHTML document.
+fn xhtml_page(title: &str, body: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE html>
+<html xmlns="http://www.w3.org/1999/xhtml" xmlns:epub="http://www.idpf.org/2007/ops">
+<head><title>{}</title></head>
+<body>
+{}
+</body>
+</html>
+"#,
+        escape_xml(title),
+        body
+    )
+}
+
+/// The title page, with the cover (if any), title, author and description.
+fn title_page(metadata: &Metadata, cover_file: Option<&str>) -> String {
+    let mut body = String::new();
+    if let Some(file) = cover_file {
+        body.push_str(&format!("<p><img src=\"{}\" alt=\"Cover\"/></p>\n", file));
+    }
+    body.push_str(&format!(
+        "<h1>{}</h1>\n<p>by {}</p>\n",
+        escape_xml(&metadata.title),
+        escape_xml(&metadata.author)
+    ));
+    if let Some(description) = &metadata.description {
+        for paragraph in description.lines().filter(|line| !line.trim().is_empty()) {
+            body.push_str(&format!("<p>{}</p>\n", escape_xml(paragraph)));
+        }
+    }
+    xhtml_page(&metadata.title, &body)
+}
+
+/// The EPUB 3 navigation document listing every chapter.
+fn nav_page(chapters: &[Chapter]) -> String {
+    let items = chapters
+        .iter()
+        .enumerate()
+        .map(|(index, chapter)| {
+            format!(
+                "<li><a href=\"chapter-{}.xhtml\">{}</a></li>",
+                index + 1,
+                escape_xml(&chapter.title)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    xhtml_page(
+        "Contents",
+        &format!(
+            "<nav epub:type=\"toc\" id=\"toc\">\n<h1>Contents</h1>\n<ol>\n<li><a href=\"title.xhtml\">Title Page</a></li>\n{}\n</ol>\n</nav>",
+            items
+        ),
+    )
+}
+
+/// The OPF package document: metadata, manifest and reading order.
+fn package_document(metadata: &Metadata, cover: Option<(&Cover, &str)>, chapters: usize) -> String {
+    let mut manifest = vec![
+        r#"<item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>"#
+            .to_string(),
+        r#"<item id="title" href="title.xhtml" media-type="application/xhtml+xml"/>"#.to_string(),
+    ];
+    if let Some((cover, file)) = cover {
+        manifest.push(format!(
+            r#"<item id="cover" href="{}" media-type="{}" properties="cover-image"/>"#,
+            file,
+            escape_xml(&cover.media_type)
+        ));
+    }
+    let mut spine = vec![r#"<itemref idref="title"/>"#.to_string()];
+    for number in 1..=chapters {
+        manifest.push(format!(
+            r#"<item id="chapter-{0}" href="chapter-{0}.xhtml" media-type="application/xhtml+xml"/>"#,
+            number
+        ));
+        spine.push(format!(r#"<itemref idref="chapter-{}"/>"#, number));
+    }
+
+    let description = metadata
+        .description
+        .as_deref()
+        .map(|d| format!("\n    <dc:description>{}</dc:description>", escape_xml(d)))
+        .unwrap_or_default();
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<package xmlns="http://www.idpf.org/2007/opf" version="3.0" unique-identifier="book-id">
+  <metadata xmlns:dc="http://purl.org/dc/elements/1.1/">
+    <dc:identifier id="book-id">urn:wattpad:story:{}</dc:identifier>
+    <dc:title>{}</dc:title>
+    <dc:creator>{}</dc:creator>
+    <dc:language>und</dc:language>{}
+    <meta property="dcterms:modified">{}</meta>
+  </metadata>
+  <manifest>
+    {}
+  </manifest>
+  <spine>
+    {}
+  </spine>
+</package>
+"#,
+        escape_xml(&metadata.id),
+        escape_xml(&metadata.title),
+        escape_xml(&metadata.author),
+        description,
+        escape_xml(&metadata.modified),
+        manifest.join("\n    "),
+        spine.join("\n    ")
+    )
+}
@@ -5,20 +5,49 @@
 //! It also includes the logic for parsing raw error messages from the Wattpad API
 //! into more specific, user-friendly error variants.
 
-use reqwest;
 use serde::Deserialize;
 use thiserror::Error;
 
 /// The primary error type for all operations in the `wattpad` crate.
+///
+/// `WattpadError` is `Send + Sync + 'static`, so `anyhow::Error`, `eyre::Report` and
+/// `Box<dyn Error + Send + Sync>` all convert from it through their blanket `From` impls,
+/// and `?` works on client methods in functions returning any of them.
+///
+/// # Examples
+/// ```no_run
+/// # use wp_mini::WattpadClient;
+/// type BoxError = Box<dyn std::error::Error + Send + Sync + 'static>;
+///
+/// # #[tokio::main]
+/// async fn main() -> Result<(), BoxError> {
+///     let client = WattpadClient::new();
+///     let user = client.user.get_user_info("test", None).await?;
+///     println!("{:?}", user.username);
+///     Ok(())
+/// }
+/// ```
 #[derive(Error, Debug)]
 pub enum WattpadError {
-    /// An error occurred during a network request (e.g., connection timeout, DNS failure).
+    /// An error occurred during a network request (e.g., connection refused, DNS failure).
     #[error("Network or request error: {0}")]
-    RequestError(#[from] reqwest::Error),
+    RequestError(reqwest::Error),
+
+    /// A request did not complete within its configured timeout, either the per-request one
+    /// or one set on `WattpadClientBuilder`.
+    #[error("Request timed out.")]
+    Timeout,
 
     /// An error occurred while parsing the JSON response from the API.
-    #[error("Failed to parse JSON response: {0}")]
-    ParseError(#[from] serde_json::Error),
+    #[error("Failed to parse JSON response from '{endpoint}' as `{type_name}`: {source}")]
+    ParseError {
+        /// The underlying deserialization error.
+        source: serde_json::Error,
+        /// The API path of the request whose response failed to parse.
+        endpoint: String,
+        /// The name of the type the response was being parsed into.
+        type_name: &'static str,
+    },
 
     /// An authentication attempt failed, likely due to invalid credentials.
     #[error("Authentication failed: Invalid credentials or missing cookies.")]
@@ -42,6 +71,50 @@ pub enum WattpadError {
         context: String,
     },
 
+    /// An input value was rejected by client-side validation before any request was sent.
+    #[error("Invalid input for '{field}': {reason}")]
+    InvalidInput {
+        /// The name of the argument that failed validation.
+        field: String,
+        /// A description of why the value was rejected.
+        reason: String,
+    },
+
+    /// The session cookies could not be written to the configured cookie store file.
+    #[error("Failed to save session to '{path}': {reason}")]
+    SessionPersistence {
+        /// The path of the cookie store file.
+        path: String,
+        /// A description of the underlying I/O or serialization failure.
+        reason: String,
+    },
+
+    /// A story could not be assembled into an export file.
+    #[error("Failed to build {format} export: {reason}")]
+    ExportFailed {
+        /// The export format being built (e.g., `EPUB`).
+        format: &'static str,
+        /// A description of the underlying failure.
+        reason: String,
+    },
+
+    /// The API rejected the request with HTTP 429 because too many requests were sent.
+    #[error("Rate limited by the API. Retry after: {retry_after:?}")]
+    RateLimited {
+        /// How long to wait before retrying, from the `Retry-After` header, if present.
+        retry_after: Option<std::time::Duration>,
+    },
+
+    /// The server returned an unsuccessful status with a body that is not a Wattpad API error,
+    /// such as an HTML error page or an empty response.
+    #[error("HTTP Error {status}: {body}")]
+    HttpError {
+        /// The HTTP status code of the response.
+        status: u16,
+        /// The raw response body.
+        body: String,
+    },
+
     /// A specific API error (code 1014) indicating the requested user was not found.
     #[error("API Error 1014: User not found.")]
     UserNotFound,
@@ -50,10 +123,69 @@ pub enum WattpadError {
     #[error("API Error 1017: Story not found.")]
     StoryNotFound,
 
+    /// The requested story part does not exist.
+    ///
+    /// The part endpoints report a missing part with a bare HTTP 404 rather than an API error
+    /// code, which `StoryClient`'s part methods surface as this variant.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::io::{Read, Write};
+    /// # use std::net::TcpListener;
+    /// # use wp_mini::{WattpadClient, WattpadError};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // A stand-in server that answers every request with a bare 404.
+    /// let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    /// let base_url = format!("http://{}", listener.local_addr().unwrap());
+    /// std::thread::spawn(move || {
+    ///     for mut stream in listener.incoming().flatten() {
+    ///         let _ = stream.read(&mut [0; 4096]);
+    ///         let _ = stream.write_all(
+    ///             b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+    ///         );
+    ///     }
+    /// });
+    ///
+    /// let client = WattpadClient::builder().base_url(&base_url).build();
+    /// let error = client.story.get_part_info(87654321, None).await.unwrap_err();
+    /// assert!(matches!(error, WattpadError::StoryPartNotFound));
+    /// # }
+    /// ```
+    #[error("Story part not found.")]
+    StoryPartNotFound,
+
     /// A specific API error (code 1018) indicating permission was denied because the user is not logged in.
     #[error("API Error 1018: Permission Denied. User not logged in.")]
     PermissionDeniedNotLoggedIn,
 
+    /// The authenticated user has already voted for the story part.
+    ///
+    /// Returned by `StoryClient::vote_part` when the API rejects the vote with HTTP 409
+    /// Conflict.
+    #[error("Already voted for this part.")]
+    AlreadyVoted,
+
+    /// The authenticated user has not voted for the story part, so there is no vote to remove.
+    ///
+    /// Returned by `StoryClient::unvote_part` when the API answers with HTTP 404 Not Found.
+    #[error("Not voted for this part.")]
+    NotVoted,
+
+    /// The story is already in the reading list.
+    ///
+    /// Returned by `ReadingListClient::add_story` when the API rejects the addition with
+    /// HTTP 409 Conflict.
+    #[error("Story already exists in the reading list.")]
+    AlreadyExists,
+
+    /// The story is not in the reading list, so it cannot be removed.
+    ///
+    /// Returned by `ReadingListClient::remove_story` when the API answers with HTTP 404 Not
+    /// Found.
+    #[error("Story not in reading list.")]
+    StoryNotInList,
+
     /// A specific API error (code 1154) indicating access to a resource was denied.
     #[error("API Error 1154: Access Denied.")]
     AccessDenied,
@@ -70,6 +202,36 @@ pub enum WattpadError {
     },
 }
 
+impl WattpadError {
+    /// Returns the variant name, used as the `error_type` metrics label.
+    #[cfg(feature = "metrics")]
+    pub(crate) fn kind(&self) -> &'static str {
+        match self {
+            Self::RequestError(_) => "RequestError",
+            Self::Timeout => "Timeout",
+            Self::ParseError { .. } => "ParseError",
+            Self::AuthenticationFailed => "AuthenticationFailed",
+            Self::AuthenticationRequired { .. } => "AuthenticationRequired",
+            Self::MissingRequiredField { .. } => "MissingRequiredField",
+            Self::InvalidInput { .. } => "InvalidInput",
+            Self::SessionPersistence { .. } => "SessionPersistence",
+            Self::ExportFailed { .. } => "ExportFailed",
+            Self::RateLimited { .. } => "RateLimited",
+            Self::HttpError { .. } => "HttpError",
+            Self::UserNotFound => "UserNotFound",
+            Self::StoryNotFound => "StoryNotFound",
+            Self::StoryPartNotFound => "StoryPartNotFound",
+            Self::PermissionDeniedNotLoggedIn => "PermissionDeniedNotLoggedIn",
+            Self::AlreadyVoted => "AlreadyVoted",
+            Self::NotVoted => "NotVoted",
+            Self::AlreadyExists => "AlreadyExists",
+            Self::StoryNotInList => "StoryNotInList",
+            Self::AccessDenied => "AccessDenied",
+            Self::ApiError { .. } => "ApiError",
+        }
+    }
+}
+
 /// An internal struct to deserialize the raw error response from the Wattpad API.
 ///
 /// This is a temporary representation that gets converted into a `WattpadError`.
@@ -81,6 +243,26 @@ pub(crate) struct ApiErrorResponse {
     pub message: String,
 }
 
+impl From<reqwest::Error> for WattpadError {
+    /// Wraps a `reqwest` error, surfacing timeouts as `WattpadError::Timeout` so they can be
+    /// told apart from other network failures.
+    fn from(e: reqwest::Error) -> Self {
+        if e.is_timeout() {
+            WattpadError::Timeout
+        } else {
+            WattpadError::RequestError(e)
+        }
+    }
+}
+
+impl From<std::convert::Infallible> for WattpadError {
+    /// Lets conversions that cannot fail, such as `Username` into itself, share an error type
+    /// with fallible ones.
+    fn from(never: std::convert::Infallible) -> Self {
+        match never {}
+    }
+}
+
 impl From<ApiErrorResponse> for WattpadError {
     /// Converts the raw API error response into a more specific and user-friendly `WattpadError`.
     ///
@@ -0,0 +1,26 @@
+use crate::field::{AuthRequiredFields, DefaultableFields};
+use strum_macros::{Display, EnumIter};
+
+/// Represents the fields that can be requested for an `Announcement` object from the Wattpad API.
+#[derive(Debug, Clone, Copy, Display, EnumIter, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[strum(serialize_all = "camelCase")]
+pub enum AnnouncementField {
+    /// The unique numerical identifier of the announcement.
+    Id,
+    /// The text body of the announcement.
+    Message,
+    /// The timestamp when the announcement was posted.
+    CreateDate,
+    /// The timestamp when the announcement was last edited.
+    ModifyDate,
+    /// The total number of times the announcement has been read.
+    ReadCount,
+}
+
+impl AuthRequiredFields for AnnouncementField {}
+
+impl DefaultableFields for AnnouncementField {
+    fn default_fields() -> Vec<Self> {
+        vec![Self::Id, Self::Message, Self::CreateDate]
+    }
+}
@@ -0,0 +1,22 @@
+use crate::field::{AuthRequiredFields, DefaultableFields};
+use strum_macros::{Display, EnumIter};
+
+/// Represents the fields that can be requested for a `Category` object from the Wattpad API.
+#[derive(Debug, Clone, Copy, Display, EnumIter, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[strum(serialize_all = "camelCase")]
+pub enum CategoryField {
+    /// The unique numerical identifier of the category.
+    Id,
+    /// The localized display name of the category.
+    Name,
+    /// The English identifier of the category, independent of the requested locale.
+    EnId,
+}
+
+impl AuthRequiredFields for CategoryField {}
+
+impl DefaultableFields for CategoryField {
+    fn default_fields() -> Vec<Self> {
+        vec![Self::Id, Self::Name]
+    }
+}
@@ -0,0 +1,52 @@
+use crate::field::user_stub_field::UserStubField;
+use crate::field::{AuthRequiredFields, DefaultableFields};
+use crate::impl_field_display;
+use strum_macros::AsRefStr;
+
+/// Represents the fields that can be requested for a `Comment` object from the Wattpad API.
+#[derive(Debug, Clone, AsRefStr, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[strum(serialize_all = "camelCase")]
+pub enum CommentField {
+    /// The unique numerical identifier of the comment.
+    Id,
+    /// The text body of the comment.
+    Text,
+
+    /// A complex field representing the author of the comment, with selectable sub-fields.
+    #[strum(disabled)]
+    User(Vec<UserStubField>),
+
+    /// The timestamp when the comment was posted.
+    CreateDate,
+    /// The timestamp when the comment was last edited.
+    ModifyDate,
+    /// The total number of votes the comment has received.
+    VoteCount,
+    /// The total number of replies to the comment.
+    ReplyCount,
+    /// A boolean flag indicating whether the comment is a reply to another comment.
+    IsReply,
+    /// The unique identifier of the parent comment, if this comment is a reply.
+    ParentId,
+    /// The index of the paragraph an inline comment is anchored to.
+    Paragraph,
+}
+
+impl_field_display!(
+    CommentField,
+    User => "user"
+);
+
+impl AuthRequiredFields for CommentField {}
+
+impl DefaultableFields for CommentField {
+    fn default_fields() -> Vec<Self> {
+        vec![
+            Self::Id,
+            Self::Text,
+            Self::User(vec![UserStubField::Username, UserStubField::Avatar]),
+            Self::CreateDate,
+            Self::VoteCount,
+        ]
+    }
+}
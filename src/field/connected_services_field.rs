@@ -0,0 +1,24 @@
+use crate::field::{AuthRequiredFields, DefaultableFields};
+use strum_macros::{Display, EnumIter};
+
+/// Represents the sub-fields for a `connectedServices` object.
+///
+/// Each sub-field indicates whether a third-party account is linked to the authenticated user.
+#[derive(Debug, Clone, Copy, Display, EnumIter, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[strum(serialize_all = "camelCase")]
+pub enum ConnectedServicesField {
+    /// Whether a Facebook account is linked.
+    Facebook,
+    /// Whether a Google account is linked.
+    Google,
+    /// Whether an Apple account is linked.
+    Apple,
+}
+
+impl AuthRequiredFields for ConnectedServicesField {}
+
+impl DefaultableFields for ConnectedServicesField {
+    fn default_fields() -> Vec<Self> {
+        vec![Self::Facebook, Self::Google, Self::Apple]
+    }
+}
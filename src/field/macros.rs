@@ -31,7 +31,7 @@
 ///     User(Vec<UserField>),
 /// }
 ///
-/// #[derive(strum_macros::AsRefStr)]
+/// #[derive(strum_macros::AsRefStr, strum_macros::Display)]
 /// #[strum(serialize_all = "camelCase")]
 /// enum UserField {
 ///     Username,
@@ -0,0 +1,47 @@
+use crate::field::user_stub_field::UserStubField;
+use crate::field::{AuthRequiredFields, DefaultableFields};
+use crate::impl_field_display;
+use strum_macros::AsRefStr;
+
+/// Represents the fields that can be requested for a `Message` object from the Wattpad API.
+#[derive(Debug, Clone, AsRefStr, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[strum(serialize_all = "camelCase")]
+pub enum MessageField {
+    /// The unique numerical identifier of the message.
+    Id,
+    /// The text body of the message.
+    Body,
+
+    /// A complex field representing the sender of the message, with selectable sub-fields.
+    #[strum(disabled)]
+    Sender(Vec<UserStubField>),
+
+    /// A complex field representing the recipient of the message, with selectable sub-fields.
+    #[strum(disabled)]
+    Recipient(Vec<UserStubField>),
+
+    /// The timestamp when the message was sent.
+    CreateDate,
+    /// A boolean flag indicating whether the message has been read.
+    Read,
+}
+
+impl_field_display!(
+    MessageField,
+    Sender => "sender",
+    Recipient => "recipient"
+);
+
+impl AuthRequiredFields for MessageField {}
+
+impl DefaultableFields for MessageField {
+    fn default_fields() -> Vec<Self> {
+        vec![
+            Self::Id,
+            Self::Body,
+            Self::Sender(vec![UserStubField::Username, UserStubField::Avatar]),
+            Self::CreateDate,
+            Self::Read,
+        ]
+    }
+}
@@ -7,23 +7,37 @@
 //! It also provides traits to manage default fields and authentication requirements.
 
 // Private modules for each field type.
+mod announcement_field;
+mod category_field;
+mod comment_field;
+mod connected_services_field;
 mod language_field;
 mod macros;
+mod message_field;
+mod notification_field;
 mod part_content_field;
 mod part_field;
 mod part_reference_field;
 mod part_stub_field;
+mod reading_list_field;
 mod story_field;
 mod text_url_field;
 mod user_field;
 mod user_stub_field;
 
 // Publicly export the field enums for use throughout the crate.
+pub use announcement_field::AnnouncementField;
+pub use category_field::CategoryField;
+pub use comment_field::CommentField;
+pub use connected_services_field::ConnectedServicesField;
 pub use language_field::LanguageField;
+pub use message_field::MessageField;
+pub use notification_field::NotificationField;
 pub use part_content_field::PartContentField;
 pub use part_field::PartField;
 pub use part_reference_field::PartReferenceField;
 pub use part_stub_field::PartStubField;
+pub use reading_list_field::ReadingListField;
 pub use story_field::StoryField;
 pub use text_url_field::TextUrlField;
 pub use user_field::UserField;
@@ -0,0 +1,34 @@
+use crate::field::{AuthRequiredFields, DefaultableFields};
+use strum_macros::{Display, EnumIter};
+
+/// Represents the fields that can be requested for a `Notification` object from the Wattpad API.
+#[derive(Debug, Clone, Copy, Display, EnumIter, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[strum(serialize_all = "camelCase")]
+pub enum NotificationField {
+    /// The unique numerical identifier of the notification.
+    Id,
+    /// The kind of event the notification is about.
+    Type,
+    /// The human-readable notification message.
+    Message,
+    /// A boolean flag indicating whether the notification has been read.
+    Read,
+    /// The timestamp when the notification was created.
+    CreateDate,
+    /// The unique identifier of the object the notification refers to.
+    RelatedEntityId,
+}
+
+impl AuthRequiredFields for NotificationField {}
+
+impl DefaultableFields for NotificationField {
+    fn default_fields() -> Vec<Self> {
+        vec![
+            Self::Id,
+            Self::Type,
+            Self::Message,
+            Self::Read,
+            Self::CreateDate,
+        ]
+    }
+}
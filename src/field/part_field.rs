@@ -40,6 +40,9 @@ pub enum PartField {
     VoteCount,
     /// The total number of reads the part has received.
     ReadCount,
+    /// A boolean flag indicating if the currently authenticated user has voted for this part.
+    /// **Requires authentication.**
+    Voted,
     /// The unique identifier of the parent story (also known as group ID).
     GroupId,
 
@@ -57,7 +60,11 @@ impl_field_display!(
     Group => "group"
 );
 
-impl AuthRequiredFields for PartField {}
+impl AuthRequiredFields for PartField {
+    fn auth_required_fields() -> Vec<Self> {
+        vec![Self::Voted]
+    }
+}
 
 impl DefaultableFields for PartField {
     fn default_fields() -> Vec<Self> {
@@ -12,6 +12,10 @@ pub enum PartReferenceField {
     Id,
     /// The timestamp when the part was created.
     CreateDate,
+    /// The title of the story part.
+    Title,
+    /// A direct URL to the story part on the Wattpad website.
+    Url,
 }
 
 impl DefaultableFields for PartReferenceField {
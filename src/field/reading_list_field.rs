@@ -0,0 +1,42 @@
+use crate::field::user_stub_field::UserStubField;
+use crate::field::{AuthRequiredFields, DefaultableFields, StoryField};
+use crate::impl_field_display;
+use strum_macros::AsRefStr;
+
+/// Represents the fields that can be requested for a `ReadingList` object from the Wattpad API.
+#[derive(Debug, Clone, AsRefStr, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[strum(serialize_all = "camelCase")]
+pub enum ReadingListField {
+    /// The unique numerical identifier of the reading list.
+    Id,
+    /// The name of the reading list.
+    Name,
+    /// The number of stories in the reading list.
+    StoryCount,
+    /// The cover image URLs of the first few stories in the list.
+    Covers,
+    /// The timestamp when the reading list was created.
+    CreateDate,
+
+    /// A complex field representing the owner of the reading list, with selectable sub-fields.
+    #[strum(disabled)]
+    User(Vec<UserStubField>),
+
+    /// A complex field for the stories in the reading list, with selectable sub-fields for each story.
+    #[strum(disabled)]
+    Stories(Vec<StoryField>),
+}
+
+impl_field_display!(
+    ReadingListField,
+    User => "user",
+    Stories => "stories"
+);
+
+impl AuthRequiredFields for ReadingListField {}
+
+impl DefaultableFields for ReadingListField {
+    fn default_fields() -> Vec<Self> {
+        vec![Self::Id, Self::Name, Self::StoryCount, Self::Covers]
+    }
+}
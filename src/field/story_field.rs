@@ -46,6 +46,8 @@ pub enum StoryField {
     Categories,
     /// A list of user-defined tags associated with the story.
     Tags,
+    /// A list of the Wattys awards the story has won (e.g., "The Wattys 2023").
+    Awards,
     /// The content rating of the story (e.g., Everyone, Mature).
     Rating,
     /// A boolean flag indicating if the story is intended for a mature audience.
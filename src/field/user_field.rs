@@ -1,8 +1,9 @@
-use crate::field::{AuthRequiredFields, DefaultableFields};
-use strum_macros::{AsRefStr, Display};
+use crate::field::{AuthRequiredFields, ConnectedServicesField, DefaultableFields};
+use crate::impl_field_display;
+use strum_macros::AsRefStr;
 
 /// Represents the fields that can be requested for a `User` object from the Wattpad API.
-#[derive(Debug, Clone, Display, AsRefStr, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[derive(Debug, Clone, AsRefStr, PartialEq, Eq, Ord, PartialOrd, Hash)]
 #[strum(serialize_all = "camelCase")]
 pub enum UserField {
     /// The user's unique, public username.
@@ -71,8 +72,32 @@ pub enum UserField {
     AllowCrawler,
     /// A deep link URL for the user's profile, often used for mobile app integration.
     Deeplink,
+
+    // Authenticated fields (only available for the currently logged-in user)
+    /// The email address of the authenticated user. Requires authentication.
+    Email,
+    /// The authenticated user's date of birth. Requires authentication.
+    Birthdate,
+    /// The authenticated user's age in years. Requires authentication.
+    Age,
+    /// Whether the authenticated user follows this user. Requires authentication.
+    Following,
+    /// Whether this user follows the authenticated user. Requires authentication.
+    Follower,
+    /// Whether the authenticated user has muted this user. Requires authentication.
+    IsMuted,
+
+    /// A complex field for the third-party accounts linked to the authenticated user, with
+    /// selectable sub-fields. Requires authentication.
+    #[strum(disabled)]
+    ConnectedServices(Vec<ConnectedServicesField>),
 }
 
+impl_field_display!(
+    UserField,
+    ConnectedServices => "connectedServices"
+);
+
 impl DefaultableFields for UserField {
     fn default_fields() -> Vec<Self> {
         vec![
@@ -93,4 +118,21 @@ impl DefaultableFields for UserField {
     }
 }
 
-impl AuthRequiredFields for UserField {}
\ No newline at end of file
+impl AuthRequiredFields for UserField {
+    fn auth_required_fields() -> Vec<Self> {
+        vec![
+            Self::Email,
+            Self::Birthdate,
+            Self::Age,
+            Self::Following,
+            Self::Follower,
+            Self::IsMuted,
+            Self::ConnectedServices(ConnectedServicesField::default_fields()),
+        ]
+    }
+
+    /// `ConnectedServices` requires authentication whatever sub-fields it selects.
+    fn auth_required(&self) -> bool {
+        matches!(self, Self::ConnectedServices(_)) || Self::auth_required_fields().contains(self)
+    }
+}
\ No newline at end of file
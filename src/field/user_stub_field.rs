@@ -18,6 +18,10 @@ pub enum UserStubField {
     FullName,
     /// A boolean flag indicating if the user is a verified account.
     Verified,
+    /// A boolean flag indicating if the user is a Wattpad Ambassador.
+    Ambassador,
+    /// A boolean flag indicating if the user's profile is private.
+    IsPrivate,
 }
 
 impl DefaultableFields for UserStubField {
@@ -0,0 +1,427 @@
+//! A tiny HTML tokenizer for the content helpers on `PartContent`.
+//!
+//! Wattpad part content is a flat sequence of simple tags (`<p>`, `<br>`, `<b>`, `<img>`, ...),
+//! so a small state machine is enough to split it into text and tags without pulling in a
+//! full HTML parser.
+
+/// A single piece of an HTML document.
+pub(crate) enum Token<'a> {
+    /// A run of text between tags, exactly as it appears in the source.
+    Text(&'a str),
+    /// An opening, closing or self-closing tag.
+    Tag(Tag),
+}
+
+/// A parsed HTML tag.
+pub(crate) struct Tag {
+    /// The lowercase tag name, e.g. `p` or `br`. Empty for comments and doctypes.
+    pub(crate) name: String,
+    /// Whether this is a closing tag such as `</p>`.
+    pub(crate) closing: bool,
+    /// The tag's attributes as lowercase name and entity-decoded value pairs.
+    attributes: Vec<(String, String)>,
+}
+
+impl Tag {
+    /// Returns the value of the attribute `name`, if present.
+    pub(crate) fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Whether the tag starts or ends a block of text, so that the text on either side of
+    /// it must not run together.
+    pub(crate) fn is_block(&self) -> bool {
+        matches!(
+            self.name.as_str(),
+            "p" | "br" | "div" | "li" | "ul" | "ol" | "blockquote" | "hr" | "h1" | "h2" | "h3"
+                | "h4" | "h5" | "h6"
+        )
+    }
+}
+
+/// The states of the tokenizer while scanning a tag.
+enum State {
+    /// Inside the tag, outside of any quoted attribute value.
+    Tag,
+    /// Inside an attribute value quoted with the given character.
+    Quoted(char),
+}
+
+/// Splits `html` into a sequence of text and tag tokens.
+pub(crate) fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        // A `<` only opens a tag when followed by a tag name, `/` or `!`; otherwise it is
+        // literal text (e.g., "a < b").
+        let tag_start = rest.char_indices().find(|&(i, c)| {
+            c == '<'
+                && rest[i + 1..]
+                    .chars()
+                    .next()
+                    .is_some_and(|next| next.is_ascii_alphabetic() || next == '/' || next == '!')
+        });
+
+        let Some((start, _)) = tag_start else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+
+        // Scan to the closing `>`, ignoring any that appear inside quoted attribute values.
+        let mut state = State::Tag;
+        let mut end = None;
+        for (i, c) in rest[start + 1..].char_indices() {
+            state = match state {
+                State::Tag if c == '>' => {
+                    end = Some(start + 1 + i);
+                    break;
+                }
+                State::Tag if c == '"' || c == '\'' => State::Quoted(c),
+                State::Quoted(quote) if c == quote => State::Tag,
+                state => state,
+            };
+        }
+
+        // An unterminated tag swallows the rest of the input, as a browser would.
+        let end = end.unwrap_or(rest.len());
+        tokens.push(Token::Tag(parse_tag(&rest[start + 1..end])));
+        rest = rest.get(end + 1..).unwrap_or("");
+    }
+
+    tokens
+}
+
+/// Parses the inside of a tag (between `<` and `>`).
+fn parse_tag(inner: &str) -> Tag {
+    if inner.starts_with('!') {
+        return Tag {
+            name: String::new(),
+            closing: false,
+            attributes: Vec::new(),
+        };
+    }
+
+    let closing = inner.starts_with('/');
+    let inner = inner.trim_start_matches('/');
+    let name_end = inner
+        .find(|c: char| !c.is_ascii_alphanumeric())
+        .unwrap_or(inner.len());
+
+    Tag {
+        name: inner[..name_end].to_ascii_lowercase(),
+        closing,
+        attributes: parse_attributes(&inner[name_end..]),
+    }
+}
+
+/// Parses `name="value"` pairs, also accepting single-quoted, unquoted and bare attributes.
+fn parse_attributes(mut rest: &str) -> Vec<(String, String)> {
+    let mut attributes = Vec::new();
+
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == '/');
+        if rest.is_empty() {
+            return attributes;
+        }
+
+        let name_end = rest
+            .find(|c: char| c.is_whitespace() || c == '=' || c == '/')
+            .unwrap_or(rest.len());
+        let name = rest[..name_end].to_ascii_lowercase();
+        rest = rest[name_end..].trim_start();
+
+        let value = match rest.strip_prefix('=') {
+            Some(after) => {
+                let after = after.trim_start();
+                let (value, remaining) = match after.chars().next() {
+                    Some(quote @ ('"' | '\'')) => {
+                        let body = &after[1..];
+                        let end = body.find(quote).unwrap_or(body.len());
+                        (&body[..end], body.get(end + 1..).unwrap_or(""))
+                    }
+                    _ => {
+                        let end = after.find(char::is_whitespace).unwrap_or(after.len());
+                        (&after[..end], &after[end..])
+                    }
+                };
+                rest = remaining;
+                decode_entities(value)
+            }
+            None => String::new(),
+        };
+
+        attributes.push((name, value));
+    }
+}
+
+/// Converts `html` to plain text: tags are removed, entities decoded, and block-level tags
+/// such as `<p>` and `<br>` become line breaks.
+pub(crate) fn to_plain_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    for token in tokenize(html) {
+        match token {
+            Token::Text(t) => text.push_str(&decode_entities(t)),
+            Token::Tag(tag) if tag.name == "br" => text.push('\n'),
+            // Start a new line for each block, without stacking blank lines between
+            // consecutive blocks like `</p><p>`.
+            Token::Tag(tag) if tag.is_block() => {
+                if !text.is_empty() && !text.ends_with('\n') {
+                    text.push('\n');
+                }
+            }
+            Token::Tag(_) => {}
+        }
+    }
+    text.trim().to_string()
+}
+
+/// Decodes the named entities common in Wattpad content, plus numeric `&#N;` and `&#xN;`
+/// references. Anything unrecognised is left as-is.
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        // Entities are short; a distant `;` belongs to something else.
+        let entity = rest
+            .char_indices()
+            .take(12)
+            .find(|&(_, c)| c == ';')
+            .and_then(|(end, _)| Some((decode_entity(&rest[1..end])?, end)));
+
+        match entity {
+            Some((c, end)) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Decodes a single entity name (without the surrounding `&` and `;`).
+fn decode_entity(name: &str) -> Option<char> {
+    match name {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some('\u{a0}'),
+        _ => {
+            let number = name.strip_prefix('#')?;
+            let code = match number.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => number.parse().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+/// Converts `html` to CommonMark Markdown.
+///
+/// Bold and italic become `**` and `*`, links become `[text](url)` and paragraphs are
+/// separated by blank lines. Underline has no Markdown equivalent and is kept as `<u>`;
+/// any other tag is dropped.
+pub(crate) fn to_markdown(html: &str) -> String {
+    let mut markdown = String::with_capacity(html.len());
+    // The targets of the links currently open, so `</a>` can emit the matching URL.
+    let mut links: Vec<Option<String>> = Vec::new();
+
+    for token in tokenize(html) {
+        let tag = match token {
+            Token::Text(t) => {
+                markdown.push_str(&escape_markdown(&decode_entities(t)));
+                continue;
+            }
+            Token::Tag(tag) => tag,
+        };
+
+        match (tag.name.as_str(), tag.closing) {
+            ("b" | "strong", _) => markdown.push_str("**"),
+            ("i" | "em", _) => markdown.push('*'),
+            ("u", false) => markdown.push_str("<u>"),
+            ("u", true) => markdown.push_str("</u>"),
+            ("a", false) => {
+                let href = tag.attribute("href").map(str::to_string);
+                if href.is_some() {
+                    markdown.push('[');
+                }
+                links.push(href);
+            }
+            ("a", true) => {
+                if let Some(Some(href)) = links.pop() {
+                    markdown.push_str("](");
+                    markdown.push_str(&href);
+                    markdown.push(')');
+                }
+            }
+            ("br", _) => markdown.push_str("  \n"),
+            _ if tag.is_block() => {
+                let trimmed = markdown.trim_end_matches([' ', '\n']).len();
+                markdown.truncate(trimmed);
+                if !markdown.is_empty() {
+                    markdown.push_str("\n\n");
+                }
+            }
+            _ => {}
+        }
+    }
+
+    markdown.trim().to_string()
+}
+
+/// Escapes the characters that would otherwise be read as inline Markdown syntax.
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '*' | '_' | '[' | ']' | '`') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Collects the `src` of every `<img>` tag, in order of appearance and without duplicates.
+///
+/// Relative URLs are resolved against `base`.
+pub(crate) fn image_urls(html: &str, base: &str) -> Vec<String> {
+    let base = reqwest::Url::parse(base).ok();
+    let mut urls: Vec<String> = Vec::new();
+
+    for token in tokenize(html) {
+        let Token::Tag(tag) = token else { continue };
+        if tag.name != "img" || tag.closing {
+            continue;
+        }
+        let Some(src) = tag.attribute("src").map(str::trim).filter(|src| !src.is_empty()) else {
+            continue;
+        };
+
+        let url = match (reqwest::Url::parse(src), &base) {
+            (Ok(url), _) => url.to_string(),
+            (Err(_), Some(base)) => match base.join(src) {
+                Ok(url) => url.to_string(),
+                Err(_) => continue,
+            },
+            (Err(_), None) => src.to_string(),
+        };
+        if !urls.contains(&url) {
+            urls.push(url);
+        }
+    }
+
+    urls
+}
+
+/// Converts `html` to well-formed XHTML body content for an EPUB chapter.
+///
+/// Only paragraphs, line breaks and basic emphasis (`b`, `strong`, `i`, `em`, `u`) are kept,
+/// without their attributes. Open elements are tracked so that every tag is closed in order,
+/// and any text outside a paragraph is wrapped in one.
+#[cfg(feature = "epub")]
+pub(crate) fn to_xhtml(html: &str) -> String {
+    let mut xhtml = String::with_capacity(html.len());
+    let mut open: Vec<String> = Vec::new();
+
+    fn close_until(xhtml: &mut String, open: &mut Vec<String>, name: &str) {
+        while let Some(tag) = open.pop() {
+            xhtml.push_str(&format!("</{}>", tag));
+            if tag == name {
+                break;
+            }
+        }
+    }
+
+    for token in tokenize(html) {
+        match token {
+            Token::Text(t) => {
+                let text = decode_entities(t);
+                if text.trim().is_empty() && open.is_empty() {
+                    continue;
+                }
+                if open.is_empty() {
+                    xhtml.push_str("<p>");
+                    open.push("p".to_string());
+                }
+                xhtml.push_str(&escape_xml(&text));
+            }
+            Token::Tag(tag) => match (tag.name.as_str(), tag.closing) {
+                ("br", _) => {
+                    if open.is_empty() {
+                        xhtml.push_str("<p>");
+                        open.push("p".to_string());
+                    }
+                    xhtml.push_str("<br/>");
+                }
+                ("p", false) => {
+                    if open.iter().any(|t| t == "p") {
+                        close_until(&mut xhtml, &mut open, "p");
+                    }
+                    xhtml.push_str("<p>");
+                    open.push("p".to_string());
+                }
+                (name @ ("b" | "strong" | "i" | "em" | "u"), false) => {
+                    if open.is_empty() {
+                        xhtml.push_str("<p>");
+                        open.push("p".to_string());
+                    }
+                    xhtml.push_str(&format!("<{}>", name));
+                    open.push(name.to_string());
+                }
+                (name @ ("p" | "b" | "strong" | "i" | "em" | "u"), true)
+                    if open.iter().any(|t| t == name) =>
+                {
+                    close_until(&mut xhtml, &mut open, name);
+                }
+                // Other block elements end the current paragraph.
+                _ if tag.is_block() => {
+                    while let Some(tag) = open.pop() {
+                        xhtml.push_str(&format!("</{}>", tag));
+                    }
+                }
+                _ => {}
+            },
+        }
+    }
+
+    while let Some(tag) = open.pop() {
+        xhtml.push_str(&format!("</{}>", tag));
+    }
+    xhtml
+}
+
+/// Escapes text for use in XML content or attribute values.
+#[cfg(feature = "epub")]
+pub(crate) fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -1,13 +1,22 @@
 //! An asynchronous, unofficial Wattpad API wrapper for Rust.
 
 // Declare the modules that make up the library.
+mod cache;
 mod client;
+#[cfg(feature = "epub")]
+mod epub;
 pub mod endpoints;
 pub mod field;
 mod error;
+mod html;
 mod model;
+mod pagination;
 pub mod types;
 
 // Publicly export the primary types for easy use.
 pub use client::WattpadClient;
-pub use error::WattpadError;
\ No newline at end of file
+pub use error::WattpadError;
+pub use pagination::PaginatedResponse;
+pub use pagination::PaginationParams;
+#[cfg(feature = "stream")]
+pub use pagination::PaginationStream;
\ No newline at end of file
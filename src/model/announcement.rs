@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents an author announcement posted on a story.
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Announcement {
+    /// The unique numerical identifier of the announcement.
+    pub id: Option<u64>,
+    /// The text body of the announcement.
+    pub message: Option<String>,
+    /// The timestamp when the announcement was posted.
+    pub create_date: Option<String>,
+    /// The timestamp when the announcement was last edited.
+    pub modify_date: Option<String>,
+    /// The total number of times the announcement has been read.
+    pub read_count: Option<i64>,
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents a story category object from the Wattpad API.
+///
+/// Named constants for the known category IDs are available in
+/// [`crate::types::category_ids`].
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Category {
+    /// The unique numerical identifier of the category.
+    pub id: Option<u32>,
+    /// The localized display name of the category (e.g., "Romance").
+    pub name: Option<String>,
+    /// The English identifier of the category, independent of the requested locale.
+    pub en_id: Option<String>,
+}
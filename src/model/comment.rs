@@ -0,0 +1,34 @@
+use crate::types::UserStubResponse;
+use serde::{Deserialize, Serialize};
+
+/// Represents a comment object from the Wattpad API.
+///
+/// Comments can be attached to a story part as a whole, or anchored inline to a
+/// specific paragraph within the part.
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Comment {
+    /// The unique numerical identifier of the comment.
+    pub id: Option<u64>,
+    /// The text body of the comment.
+    pub text: Option<String>,
+    /// A stub object representing the author of the comment.
+    pub user: Option<UserStubResponse>,
+    /// The timestamp when the comment was posted.
+    pub create_date: Option<String>,
+    /// The timestamp when the comment was last edited.
+    pub modify_date: Option<String>,
+    /// The total number of votes the comment has received.
+    pub vote_count: Option<i64>,
+    /// The total number of replies to the comment.
+    pub reply_count: Option<i64>,
+    /// A boolean flag indicating whether the comment is a reply to another comment.
+    pub is_reply: Option<bool>,
+    /// The unique identifier of the parent comment, if this comment is a reply.
+    pub parent_id: Option<u64>,
+    /// The index of the paragraph an inline comment is anchored to.
+    ///
+    /// This is `None` for comments that apply to the story part as a whole.
+    pub paragraph: Option<u32>,
+}
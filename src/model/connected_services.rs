@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents the third-party accounts linked to the authenticated user.
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectedServices {
+    /// Whether a Facebook account is linked.
+    pub facebook: Option<bool>,
+    /// Whether a Google account is linked.
+    pub google: Option<bool>,
+    /// Whether an Apple account is linked.
+    pub apple: Option<bool>,
+}
@@ -1,7 +1,8 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Represents a language object from the Wattpad API.
-#[derive(Debug, Deserialize, Clone)]
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Language {
     /// The unique numerical identifier of the language.
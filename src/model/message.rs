@@ -0,0 +1,23 @@
+use crate::types::UserStubResponse;
+use serde::{Deserialize, Serialize};
+
+/// Represents a private message from the Wattpad API.
+///
+/// Messages are only available to the authenticated user who sent or received them.
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Message {
+    /// The unique numerical identifier of the message.
+    pub id: Option<u64>,
+    /// The text body of the message.
+    pub body: Option<String>,
+    /// A stub object representing the user who sent the message.
+    pub sender: Option<UserStubResponse>,
+    /// A stub object representing the user the message was sent to.
+    pub recipient: Option<UserStubResponse>,
+    /// The timestamp when the message was sent.
+    pub create_date: Option<String>,
+    /// A boolean flag indicating whether the message has been read.
+    pub read: Option<bool>,
+}
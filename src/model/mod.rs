@@ -5,23 +5,45 @@
 //! the library. They are exposed to the end-user through the public type aliases
 //! in the `crate::types` module.
 
-// mod category;
+mod announcement;
+mod category;
+mod comment;
+mod connected_services;
 mod language;
+mod message;
+mod notification;
 mod part;
 mod part_content;
 mod part_reference;
 mod part_stub;
+mod reading_list;
+mod reading_position;
 mod story;
 mod text_url;
 mod user;
 mod user_stub;
+mod writing_stats;
 
+pub(crate) use announcement::*;
+pub(crate) use category::*;
+pub(crate) use comment::*;
+pub(crate) use connected_services::*;
 pub(crate) use language::*;
+pub(crate) use message::*;
+pub(crate) use notification::*;
 pub(crate) use part::*;
 pub(crate) use part_content::*;
 pub(crate) use part_reference::*;
 pub(crate) use part_stub::*;
+pub(crate) use reading_list::*;
+pub(crate) use reading_position::*;
 pub(crate) use story::*;
 pub(crate) use text_url::*;
 pub(crate) use user::*;
-pub(crate) use user_stub::*;
\ No newline at end of file
+pub(crate) use user_stub::*;
+pub(crate) use writing_stats::*;
+
+/// Formats an optional field for a `Display` summary, falling back to `<unknown>`.
+fn or_unknown<T: std::fmt::Display>(value: Option<T>) -> String {
+    value.map_or_else(|| "<unknown>".to_string(), |value| value.to_string())
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents a notification object from the Wattpad API.
+///
+/// Notifications are only available to the authenticated user they belong to.
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Notification {
+    /// The unique numerical identifier of the notification.
+    pub id: Option<u64>,
+    /// The kind of event the notification is about (e.g., a new follower or comment).
+    #[serde(rename = "type")]
+    pub type_: Option<String>,
+    /// The human-readable notification message.
+    pub message: Option<String>,
+    /// A boolean flag indicating whether the notification has been read.
+    pub read: Option<bool>,
+    /// The timestamp when the notification was created.
+    pub create_date: Option<String>,
+    /// The unique identifier of the object the notification refers to (e.g., a story or comment).
+    pub related_entity_id: Option<u64>,
+}
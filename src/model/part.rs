@@ -1,8 +1,13 @@
+use crate::field::StoryField;
 use crate::types::{StoryResponse, TextUrlResponse};
-use serde::Deserialize;
+use crate::{WattpadClient, WattpadError};
+use super::or_unknown;
+use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// Represents a full story part object from the Wattpad API.
-#[derive(Debug, Deserialize, Clone)]
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Part {
     /// The unique numerical identifier of the story part.
@@ -49,4 +54,66 @@ pub struct Part {
     pub group: Option<Box<StoryResponse>>,
     /// A boolean flag indicating whether the part has been deleted.
     pub deleted: Option<bool>,
-}
\ No newline at end of file
+}
+
+impl Part {
+    /// Fetches the full story that this part belongs to.
+    ///
+    /// This is the counterpart of `Story::fetch_all_parts_full`, navigating from a part back
+    /// to its story using `group_id`.
+    ///
+    /// # Arguments
+    /// * `client` - An instance of `WattpadClient` to use for the API request.
+    /// * `fields` - An optional slice of `StoryField` specifying which fields to retrieve.
+    ///   If `None`, a comprehensive list of all known fields will be requested by default.
+    ///
+    /// # Returns
+    /// A `Result` containing the parent `StoryResponse` on success.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::MissingRequiredField` if `group_id` is `None` or not a numeric
+    /// ID, or another `WattpadError` if the API request fails.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::{WattpadClient, WattpadError};
+    /// # use wp_mini::field::{PartField, StoryField};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), WattpadError> {
+    /// let client = WattpadClient::new();
+    /// let part = client.story.get_part_info(87654321, Some(&[PartField::GroupId])).await?;
+    ///
+    /// let story = part.fetch_parent_story(&client, Some(&[StoryField::Title])).await?;
+    /// println!("Part of {:?}", story.title);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_parent_story(
+        &self,
+        client: &WattpadClient,
+        fields: Option<&[StoryField]>,
+    ) -> Result<StoryResponse, WattpadError> {
+        let story_id = self
+            .group_id
+            .as_deref()
+            .and_then(|id| id.parse::<u64>().ok())
+            .ok_or_else(|| WattpadError::MissingRequiredField {
+                field: "group_id".to_string(),
+                context: "Cannot fetch the parent story without a numeric group_id.".to_string(),
+            })?;
+
+        client.story.get_story_info(story_id, fields).await
+    }
+}
+
+impl fmt::Display for Part {
+    /// Formats a one-line summary, e.g. `Part #456: "Chapter 1"`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Part #{}: \"{}\"",
+            or_unknown(self.id),
+            or_unknown(self.title.as_deref()),
+        )
+    }
+}
@@ -1,7 +1,16 @@
-use serde::Deserialize;
+use crate::html;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// The host that relative image URLs in part content are served from.
+const IMAGE_BASE_URL: &str = "https://img.wattpad.com";
+
+/// The reading time per word at 200 words per minute.
+const MILLIS_PER_WORD: u64 = 60_000 / 200;
 
 /// Represents the content of a story part, typically returned in a structured JSON format.
-#[derive(Debug, Deserialize, Clone)]
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct PartContent {
     /// The full text content of the story part.
@@ -9,4 +18,145 @@ pub struct PartContent {
     /// A hash of the text content, likely used for caching or integrity checks.
     #[serde(rename = "text_hash")]
     pub text_hash: Option<String>,
-}
\ No newline at end of file
+}
+
+impl PartContent {
+    /// Counts the words in the content, ignoring HTML markup.
+    ///
+    /// The content is converted with `to_plain_text` before being split on whitespace, so
+    /// tags and their attributes are not counted as words.
+    ///
+    /// # Returns
+    /// The number of words, or `None` if `text` is `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// use wp_mini::types::PartContentResponse;
+    ///
+    /// let content = PartContentResponse {
+    ///     text: Some(r#"<p data-p-id="1">Once upon a time</p><p>there was a <b>dragon</b>.</p>"#.to_string()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(content.word_count(), Some(8));
+    /// ```
+    pub fn word_count(&self) -> Option<usize> {
+        Some(self.to_plain_text()?.split_whitespace().count())
+    }
+
+    /// Converts the HTML content to plain text.
+    ///
+    /// All tags are removed, common entities (`&amp;`, `&lt;`, `&gt;`, `&quot;`, `&nbsp;` and
+    /// numeric `&#N;` references) are decoded, and paragraphs and `<br>` tags become line breaks.
+    ///
+    /// # Returns
+    /// The plain text, or `None` if `text` is `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// use wp_mini::types::PartContentResponse;
+    ///
+    /// let content = PartContentResponse {
+    ///     text: Some("<p>Fish &amp; chips</p><p>Line one<br>line&#160;two</p>".to_string()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     content.to_plain_text().as_deref(),
+    ///     Some("Fish & chips\nLine one\nline\u{a0}two")
+    /// );
+    /// ```
+    pub fn to_plain_text(&self) -> Option<String> {
+        let text = self.text.as_deref()?;
+        Some(html::to_plain_text(text))
+    }
+
+    /// Estimates how long the content takes to read, at 200 words per minute.
+    ///
+    /// # Returns
+    /// The estimated reading time, or `None` if `text` is `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    /// use wp_mini::types::PartContentResponse;
+    ///
+    /// let content = PartContentResponse {
+    ///     text: Some("word ".repeat(1000)),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(content.estimated_reading_time(), Some(Duration::from_secs(300)));
+    /// ```
+    pub fn estimated_reading_time(&self) -> Option<Duration> {
+        let words = self.word_count()? as u64;
+        Some(Duration::from_millis(words * MILLIS_PER_WORD))
+    }
+
+    /// Converts the HTML content to CommonMark Markdown.
+    ///
+    /// Bold becomes `**text**`, italic `*text*`, links `[text](url)`, and paragraphs are
+    /// separated by blank lines. Underline has no Markdown equivalent and is kept as
+    /// `<u>text</u>`. Any other tag is removed, and literal Markdown characters in the text
+    /// are escaped.
+    ///
+    /// # Returns
+    /// The Markdown text, or `None` if `text` is `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// use wp_mini::types::PartContentResponse;
+    ///
+    /// let content = PartContentResponse {
+    ///     text: Some(
+    ///         r#"<p>A <b>bold</b> and <i>quiet</i> start.</p><p><u>See</u> <a href="https://example.com">this</a>.</p>"#
+    ///             .to_string(),
+    ///     ),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     content.to_markdown().as_deref(),
+    ///     Some("A **bold** and *quiet* start.\n\n<u>See</u> [this](https://example.com).")
+    /// );
+    /// ```
+    pub fn to_markdown(&self) -> Option<String> {
+        let text = self.text.as_deref()?;
+        Some(html::to_markdown(text))
+    }
+
+    /// Collects the URLs of all images embedded in the content.
+    ///
+    /// URLs are taken from the `src` attribute of each `<img>` tag, deduplicated, and kept in
+    /// the order they appear. Relative URLs are resolved against `https://img.wattpad.com`.
+    ///
+    /// # Returns
+    /// The image URLs, or an empty `Vec` if there are none or `text` is `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// use wp_mini::types::PartContentResponse;
+    ///
+    /// let content = PartContentResponse {
+    ///     text: Some(
+    ///         r#"<p><img src="/story_parts/1/images/a.jpg"></p><p><img src="https://example.com/b.png"/><img src="/story_parts/1/images/a.jpg"></p>"#
+    ///             .to_string(),
+    ///     ),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     content.extract_image_urls(),
+    ///     vec![
+    ///         "https://img.wattpad.com/story_parts/1/images/a.jpg",
+    ///         "https://example.com/b.png",
+    ///     ]
+    /// );
+    /// ```
+    pub fn extract_image_urls(&self) -> Vec<String> {
+        match self.text.as_deref() {
+            Some(text) => html::image_urls(text, IMAGE_BASE_URL),
+            None => Vec::new(),
+        }
+    }
+}
@@ -1,19 +1,24 @@
 use crate::types::PartResponse;
 use crate::{WattpadClient, WattpadError};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Represents a lightweight reference to a story part.
 ///
 /// This struct is often used in lists (e.g., a story's list of parts) where sending
 /// the full part data for each item would be inefficient. It can be "upgraded" to a
 /// full `Part` object using the `fetch_full_part` method.
-#[derive(Debug, Deserialize, Clone)]
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct PartReference {
     /// The unique numerical identifier of the story part.
     pub id: Option<u64>,
     /// The timestamp when the part was created.
     pub create_date: Option<String>,
+    /// The title of the story part.
+    pub title: Option<String>,
+    /// A direct URL to the story part on the Wattpad website.
+    pub url: Option<String>,
 }
 
 impl PartReference {
@@ -40,7 +45,7 @@ impl PartReference {
     /// // Assume `part_ref` is a PartReference obtained from a story object.
     /// use wp_mini::types::PartReferenceResponse;
     /// use wp_mini::WattpadClient;
-    /// let part_ref = PartReferenceResponse { id: Some(12345), create_date: None };
+    /// let part_ref = PartReferenceResponse { id: Some(12345), create_date: None, title: None, url: None };
     /// let client = WattpadClient::new();
     ///
     /// // Fetch the full details for the part
@@ -1,6 +1,6 @@
-use crate::types::{PartResponse, TextUrlResponse};
+use crate::types::{PartContentResponse, PartResponse, TextUrlResponse};
 use crate::{WattpadClient, WattpadError};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Represents a lightweight stub of a story part.
 ///
@@ -8,7 +8,8 @@ use serde::Deserialize;
 /// often used in lists where sending the complete data for every part would be
 /// inefficient. It can be "upgraded" to a full `Part` object using the
 /// `fetch_full_part` method.
-#[derive(Debug, Deserialize, Clone)]
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct PartStub {
     /// The unique numerical identifier of the story part.
@@ -97,4 +98,126 @@ impl PartStub {
             })
         }
     }
-}
\ No newline at end of file
+
+    /// Fetches the raw text content of this part.
+    ///
+    /// A convenience wrapper around `StoryClient::get_part_content_raw` using the stub's `id`.
+    ///
+    /// # Arguments
+    /// * `client` - An instance of `WattpadClient` to use for the API request.
+    ///
+    /// # Returns
+    /// A `Result` containing the part's raw content (HTML) on success.
+    ///
+    /// # Errors
+    /// Returns a `WattpadError` if the `id` field on this stub is `None`, or if the
+    /// underlying API request fails.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::{WattpadClient, WattpadError};
+    /// # use wp_mini::types::PartStubResponse;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), WattpadError> {
+    /// let client = WattpadClient::new();
+    /// let part_stub = PartStubResponse { id: Some(12345), ..Default::default() };
+    ///
+    /// let html = part_stub.fetch_content_raw(&client).await?;
+    /// println!("Content is {} bytes long", html.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_content_raw(&self, client: &WattpadClient) -> Result<String, WattpadError> {
+        client.story.get_part_content_raw(self.require_id("content")?).await
+    }
+
+    /// Fetches the content of this part as a structured `PartContentResponse`.
+    ///
+    /// A convenience wrapper around `StoryClient::get_part_content_json` using the stub's `id`.
+    ///
+    /// # Arguments
+    /// * `client` - An instance of `WattpadClient` to use for the API request.
+    ///
+    /// # Returns
+    /// A `Result` containing the part's content on success.
+    ///
+    /// # Errors
+    /// Returns a `WattpadError` if the `id` field on this stub is `None`, or if the
+    /// underlying API request fails.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::{WattpadClient, WattpadError};
+    /// # use wp_mini::types::PartStubResponse;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), WattpadError> {
+    /// let client = WattpadClient::new();
+    /// let part_stub = PartStubResponse { id: Some(12345), ..Default::default() };
+    ///
+    /// let content = part_stub.fetch_content_json(&client).await?;
+    /// println!("Word count: {:?}", content.word_count());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_content_json(
+        &self,
+        client: &WattpadClient,
+    ) -> Result<PartContentResponse, WattpadError> {
+        client.story.get_part_content_json(self.require_id("content")?).await
+    }
+
+    /// A private helper that returns the stub's `id`, or a `MissingRequiredField` error
+    /// naming what could not be fetched without it.
+    fn require_id(&self, what: &str) -> Result<u64, WattpadError> {
+        self.id.ok_or_else(|| WattpadError::MissingRequiredField {
+            field: "id".to_string(),
+            context: format!("Cannot fetch {} without an id.", what),
+        })
+    }
+}
+
+impl From<PartStub> for PartResponse {
+    /// Converts a stub into an **incomplete** `PartResponse`.
+    ///
+    /// Every field the two types share is carried over. `group_id` and `group` are always
+    /// `None`, since a stub does not know its parent story; use `fetch_full_part` if those
+    /// or any fields missing from the stub are needed.
+    ///
+    /// # Examples
+    /// ```
+    /// use wp_mini::types::{PartResponse, PartStubResponse};
+    ///
+    /// let stub = PartStubResponse {
+    ///     id: Some(12345),
+    ///     title: Some("Chapter 1".to_string()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let part = PartResponse::from(stub);
+    /// assert_eq!(part.id, Some(12345));
+    /// assert_eq!(part.group_id, None);
+    /// ```
+    fn from(stub: PartStub) -> Self {
+        PartResponse {
+            id: stub.id,
+            title: stub.title,
+            url: stub.url,
+            text_url: stub.text_url,
+            rating: stub.rating,
+            draft: stub.draft,
+            modify_date: stub.modify_date,
+            create_date: stub.create_date,
+            has_banned_images: stub.has_banned_images,
+            length: stub.length,
+            video_id: stub.video_id,
+            photo_url: stub.photo_url,
+            comment_count: stub.comment_count,
+            vote_count: stub.vote_count,
+            read_count: stub.read_count,
+            group_id: None,
+            voted: stub.voted,
+            group: None,
+            deleted: stub.deleted,
+        }
+    }
+}
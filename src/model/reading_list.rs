@@ -0,0 +1,25 @@
+use crate::types::{StoryResponse, UserStubResponse};
+use serde::{Deserialize, Serialize};
+
+/// Represents a reading list object from the Wattpad API.
+///
+/// A reading list is a user-curated, named collection of stories.
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadingList {
+    /// The unique numerical identifier of the reading list.
+    pub id: Option<u64>,
+    /// The name of the reading list.
+    pub name: Option<String>,
+    /// A stub object representing the owner of the reading list.
+    pub user: Option<UserStubResponse>,
+    /// The number of stories in the reading list.
+    pub story_count: Option<i64>,
+    /// The stories contained in the reading list.
+    pub stories: Option<Vec<StoryResponse>>,
+    /// The cover image URLs of the first few stories in the list, used for previews.
+    pub covers: Option<Vec<String>>,
+    /// The timestamp when the reading list was created.
+    pub create_date: Option<String>,
+}
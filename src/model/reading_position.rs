@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents the authenticated user's reading position in a story.
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadingPosition {
+    /// The unique identifier of the part the user last read.
+    pub part_id: Option<u64>,
+    /// The offset within that part where the user stopped reading.
+    pub position: Option<u64>,
+    /// A boolean flag indicating whether the user has finished the story.
+    pub reading_complete: Option<bool>,
+}
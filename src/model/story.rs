@@ -1,9 +1,16 @@
-use super::Language;
-use crate::types::{PartReferenceResponse, PartStubResponse, UserStubResponse};
-use serde::Deserialize;
+use super::{or_unknown, Language};
+use crate::field::{PartField, UserField};
+use crate::types::{
+    PartReferenceResponse, PartResponse, PartStubResponse, UserResponse, UserStubResponse,
+};
+use crate::{WattpadClient, WattpadError};
+use futures_util::future::join_all;
+use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// Represents a full story object from the Wattpad API.
-#[derive(Debug, Deserialize, Clone)]
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Story {
     /// The unique identifier of the story.
@@ -39,6 +46,8 @@ pub struct Story {
     pub categories: Option<Vec<i64>>,
     /// A list of user-defined tags associated with the story.
     pub tags: Option<Vec<String>>,
+    /// A list of the Wattys awards the story has won (e.g., "The Wattys 2023").
+    pub awards: Option<Vec<String>>,
     /// The content rating of the story (e.g., Everyone, Mature).
     pub rating: Option<i64>,
     /// A boolean flag indicating if the story is intended for a mature audience.
@@ -59,4 +68,256 @@ pub struct Story {
     pub parts: Option<Vec<PartStubResponse>>,
     /// A boolean flag indicating whether the story has been deleted.
     pub deleted: Option<bool>,
-}
\ No newline at end of file
+}
+
+impl Story {
+    /// Returns an iterator over the story's part stubs, in reading order.
+    ///
+    /// The iterator is empty if `parts` is `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// use wp_mini::types::{PartStubResponse, StoryResponse};
+    ///
+    /// let story = StoryResponse {
+    ///     parts: Some(vec![
+    ///         PartStubResponse { id: Some(1), draft: Some(false), ..Default::default() },
+    ///         PartStubResponse { id: Some(2), draft: Some(true), ..Default::default() },
+    ///     ]),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let published = story.parts_iter().filter(|part| part.draft != Some(true)).count();
+    /// assert_eq!(published, 1);
+    ///
+    /// assert_eq!(StoryResponse::default().parts_iter().count(), 0);
+    /// ```
+    pub fn parts_iter(&self) -> impl Iterator<Item = &PartStubResponse> {
+        self.parts.iter().flatten()
+    }
+
+    /// Estimates the story's total word count from the reading time of its parts.
+    ///
+    /// The API does not report a word count, so this sums the `length` of every part stub
+    /// (an estimated reading time in seconds) and converts it at an assumed reading speed of
+    /// 200 words per minute. If no part has a `length`, the story's own `length` is used.
+    ///
+    /// The result is only a rough approximation: it inherits whatever rounding Wattpad
+    /// applies to reading times, and real reading speeds vary widely.
+    ///
+    /// # Returns
+    /// The estimated word count, or `None` if neither the parts nor the story have a `length`.
+    ///
+    /// # Examples
+    /// ```
+    /// use wp_mini::types::{PartStubResponse, StoryResponse};
+    ///
+    /// let story = StoryResponse {
+    ///     parts: Some(vec![
+    ///         PartStubResponse { length: Some(300), ..Default::default() },
+    ///         PartStubResponse { length: Some(600), ..Default::default() },
+    ///     ]),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// // 15 minutes of reading at 200 words per minute.
+    /// assert_eq!(story.computed_word_count(), Some(3000));
+    /// assert_eq!(StoryResponse::default().computed_word_count(), None);
+    /// ```
+    pub fn computed_word_count(&self) -> Option<i64> {
+        const WORDS_PER_MINUTE: i64 = 200;
+
+        let seconds = self
+            .parts_iter()
+            .filter_map(|part| part.length)
+            .reduce(|total, length| total + length)
+            .or(self.length)?;
+        Some(seconds * WORDS_PER_MINUTE / 60)
+    }
+
+    /// Fetches the full `Part` object for every part stub in `parts`.
+    ///
+    /// All parts are requested concurrently, and the results are returned in the same order
+    /// as the stubs.
+    ///
+    /// # Arguments
+    /// * `client` - An instance of `WattpadClient` to use for the API requests.
+    /// * `fields` - An optional slice of `PartField`s to request for each part. If `None`,
+    ///   default fields are used.
+    ///
+    /// # Returns
+    /// A `Result` containing the full parts, or an empty `Vec` if `parts` is `None` or empty.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::MissingRequiredField` if any stub has no `id`, or the first
+    /// error from the underlying API requests.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::{WattpadClient, WattpadError};
+    /// # use wp_mini::field::{PartStubField, StoryField};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), WattpadError> {
+    /// let client = WattpadClient::new();
+    /// let story = client
+    ///     .story
+    ///     .get_story_info(12345, Some(&[StoryField::Parts(vec![PartStubField::Id])]))
+    ///     .await?;
+    ///
+    /// for part in story.fetch_all_parts_full(&client, None).await? {
+    ///     println!("{}", part);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_all_parts_full(
+        &self,
+        client: &WattpadClient,
+        fields: Option<&[PartField]>,
+    ) -> Result<Vec<PartResponse>, WattpadError> {
+        let Some(parts) = self.parts.as_deref() else {
+            return Ok(Vec::new());
+        };
+
+        let requests = parts.iter().map(|stub| async move {
+            match stub.id {
+                Some(id) => client.story.get_part_info(id, fields).await,
+                None => Err(WattpadError::MissingRequiredField {
+                    field: "id".to_string(),
+                    context: "Cannot fetch full part without an id.".to_string(),
+                }),
+            }
+        });
+
+        join_all(requests).await.into_iter().collect()
+    }
+
+    /// Fetches the full profile of the story's author.
+    ///
+    /// This is a convenience method that uses the `username` of the embedded `user` stub,
+    /// like `UserStub::fetch_full_profile`, but lets the caller choose the fields.
+    ///
+    /// # Arguments
+    /// * `client` - An instance of `WattpadClient` to use for the API request.
+    /// * `fields` - An optional slice of `UserField` specifying which fields to retrieve.
+    ///   If `None`, a default set of fields will be requested.
+    ///
+    /// # Returns
+    /// A `Result` containing the author's `UserResponse` on success.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::MissingRequiredField` if `user` is `None` or has no
+    /// `username`, or another `WattpadError` if the API request fails.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::{WattpadClient, WattpadError};
+    /// # use wp_mini::field::{StoryField, UserField, UserStubField};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), WattpadError> {
+    /// let client = WattpadClient::new();
+    /// let story = client
+    ///     .story
+    ///     .get_story_info(12345, Some(&[StoryField::User(vec![UserStubField::Username])]))
+    ///     .await?;
+    ///
+    /// let author = story.fetch_author_profile(&client, Some(&[UserField::NumFollowers])).await?;
+    /// println!("{:?} followers", author.num_followers);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_author_profile(
+        &self,
+        client: &WattpadClient,
+        fields: Option<&[UserField]>,
+    ) -> Result<UserResponse, WattpadError> {
+        match self.user.as_ref().and_then(|user| user.username.as_deref()) {
+            Some(username) => client.user.get_user_info(username, fields).await,
+            None => Err(WattpadError::MissingRequiredField {
+                field: "user.username".to_string(),
+                context: "Cannot fetch the author profile without the author's username."
+                    .to_string(),
+            }),
+        }
+    }
+}
+
+impl IntoIterator for Story {
+    type Item = PartStubResponse;
+    type IntoIter = std::iter::Flatten<std::option::IntoIter<Vec<PartStubResponse>>>;
+
+    /// Consumes the story, yielding its part stubs in reading order.
+    ///
+    /// # Examples
+    /// ```
+    /// use wp_mini::types::{PartStubResponse, StoryResponse};
+    ///
+    /// let story = StoryResponse {
+    ///     parts: Some(vec![PartStubResponse { id: Some(1), ..Default::default() }]),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let ids: Vec<_> = story.into_iter().map(|part| part.id).collect();
+    /// assert_eq!(ids, vec![Some(1)]);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        self.parts.into_iter().flatten()
+    }
+}
+
+impl<'a> IntoIterator for &'a Story {
+    type Item = &'a PartStubResponse;
+    type IntoIter = std::iter::Flatten<std::option::Iter<'a, Vec<PartStubResponse>>>;
+
+    /// Yields references to the story's part stubs in reading order.
+    ///
+    /// # Examples
+    /// ```
+    /// use wp_mini::types::{PartStubResponse, StoryResponse};
+    ///
+    /// let story = StoryResponse {
+    ///     parts: Some(vec![PartStubResponse { title: Some("Chapter 1".into()), ..Default::default() }]),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// for part in &story {
+    ///     assert_eq!(part.title.as_deref(), Some("Chapter 1"));
+    /// }
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        self.parts.iter().flatten()
+    }
+}
+
+impl fmt::Display for Story {
+    /// Formats a one-line summary, e.g. `Story #123: "Title" by @author (12 parts, 3400 reads)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use wp_mini::types::StoryResponse;
+    ///
+    /// let story = StoryResponse {
+    ///     id: Some("123".to_string()),
+    ///     title: Some("Title".to_string()),
+    ///     num_parts: Some(12),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     story.to_string(),
+    ///     "Story #123: \"Title\" by @<unknown> (12 parts, <unknown> reads)"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let author = self.user.as_ref().and_then(|user| user.username.as_deref());
+        write!(
+            f,
+            "Story #{}: \"{}\" by @{} ({} parts, {} reads)",
+            or_unknown(self.id.as_deref()),
+            or_unknown(self.title.as_deref()),
+            or_unknown(author),
+            or_unknown(self.num_parts),
+            or_unknown(self.read_count),
+        )
+    }
+}
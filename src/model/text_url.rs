@@ -1,10 +1,12 @@
-use serde::Deserialize;
+use crate::{WattpadClient, WattpadError};
+use serde::{Deserialize, Serialize};
 
 /// Represents a `text_url` object from the Wattpad API.
 ///
 /// This object provides URLs and tokens for accessing the actual text content
 /// of a story part, which is often served from a separate, temporary URL.
-#[derive(Debug, Deserialize, Clone)]
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct TextUrl {
     /// The direct, often temporary and expiring, URL to the story part's text content.
@@ -13,4 +15,65 @@ pub struct TextUrl {
     /// likely after the original one expires.
     #[serde(rename = "refresh_token")]
     pub refresh_token: Option<String>,
-}
\ No newline at end of file
+}
+
+impl TextUrl {
+    /// Downloads the text content directly from the URL in `text`.
+    ///
+    /// Content URLs point at a CDN rather than the Wattpad API, so this skips the usual
+    /// request handling (authentication checks, API error parsing) and simply fetches the
+    /// URL with the client's underlying HTTP connection.
+    ///
+    /// # Arguments
+    /// * `client` - An instance of `WattpadClient` to use for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the raw content on success.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::MissingRequiredField` if `text` is `None`, or
+    /// `WattpadError::HttpError` if the server responds with an unsuccessful status
+    /// (e.g., because the URL has expired).
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::{WattpadClient, WattpadError};
+    /// # use wp_mini::field::{PartField, TextUrlField};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), WattpadError> {
+    /// let client = WattpadClient::new();
+    /// let part = client
+    ///     .story
+    ///     .get_part_info(12345, Some(&[PartField::TextUrl(vec![TextUrlField::Text])]))
+    ///     .await?;
+    ///
+    /// if let Some(text_url) = part.text_url {
+    ///     let content = text_url.fetch_content(&client).await?;
+    ///     println!("Fetched {} bytes of content", content.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_content(&self, client: &WattpadClient) -> Result<String, WattpadError> {
+        let Some(url) = self.text.as_deref() else {
+            return Err(WattpadError::MissingRequiredField {
+                field: "text".to_string(),
+                context: "Cannot fetch content without a text URL.".to_string(),
+            });
+        };
+
+        let response = client.http.get(url).send().await?;
+        let status = response.status();
+        let body = response.text().await?;
+
+        if status.is_success() {
+            Ok(body)
+        } else {
+            Err(WattpadError::HttpError {
+                status: status.as_u16(),
+                body,
+            })
+        }
+    }
+}
+
@@ -1,11 +1,15 @@
-use serde::Deserialize;
+use super::or_unknown;
+use crate::types::ConnectedServicesResponse;
+use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// Represents a full user object from the Wattpad API.
 ///
 /// This struct contains all the publicly available fields for a user's profile.
 /// Some fields, noted in the comments, are only available when making an authenticated
 /// request for the current user's own profile.
-#[derive(Debug, Deserialize, Clone)]
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct User {
     /// The user's unique, public username.
@@ -78,16 +82,34 @@ pub struct User {
     /// A deep link URL for the user's profile, often used for mobile app integration.
     pub deeplink: Option<String>,
 
-    // // Authenticated fields (only available for the currently logged-in user)
-    // pub follower: Option<bool>,
-    // pub is_muted: Option<bool>,
-    // pub following: Option<bool>,
-    // pub birthdate: Option<String>,
+    // Authenticated fields (only available for the currently logged-in user)
+    /// The email address of the authenticated user.
+    pub email: Option<String>,
+    /// The authenticated user's date of birth.
+    pub birthdate: Option<String>,
+    /// The authenticated user's age in years.
+    pub age: Option<i64>,
+    /// Whether the authenticated user follows this user.
+    pub following: Option<bool>,
+    /// Whether this user follows the authenticated user.
+    pub follower: Option<bool>,
+    /// Whether the authenticated user has muted this user.
+    pub is_muted: Option<bool>,
+    /// The third-party accounts linked to the authenticated user.
+    pub connected_services: Option<ConnectedServicesResponse>,
     // pub inbox: Option<Inbox>,
     // pub notifications: Option<Notifications>,
-    // #[serde(rename = "connectedServices")]
-    // pub connected_services: Option<ConnectedServices>,
-    // pub age: Option<i64>,
-    // pub email: Option<String>,
     // pub has_password: Option<bool>,
-}
\ No newline at end of file
+}
+
+impl fmt::Display for User {
+    /// Formats a one-line summary, e.g. `@username (1500 followers)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "@{} ({} followers)",
+            or_unknown(self.username.as_deref()),
+            or_unknown(self.num_followers),
+        )
+    }
+}
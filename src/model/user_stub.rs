@@ -1,6 +1,8 @@
+use crate::field::StoryField;
 use crate::model::User;
-use crate::{WattpadClient, WattpadError};
-use serde::Deserialize;
+use crate::types::StoryResponse;
+use crate::{PaginationParams, WattpadClient, WattpadError};
+use serde::{Deserialize, Serialize};
 
 /// Represents a lightweight stub of a user.
 ///
@@ -8,7 +10,8 @@ use serde::Deserialize;
 /// It's often embedded in other API responses (like stories or comments) to avoid
 /// sending redundant data. It can be "upgraded" to a full `User` object using the
 /// `fetch_full_profile` method.
-#[derive(Debug, Deserialize, Clone)]
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct UserStub {
     /// The user's unique username.
     #[serde(rename = "name")]
@@ -19,6 +22,11 @@ pub struct UserStub {
     pub fullname: Option<String>,
     /// A boolean flag indicating if the user is a verified account.
     pub verified: Option<bool>,
+    /// A boolean flag indicating if the user is a Wattpad Ambassador.
+    pub ambassador: Option<bool>,
+    /// A boolean flag indicating if the user's profile is private.
+    #[serde(rename = "isPrivate")]
+    pub is_private: Option<bool>,
 }
 
 impl UserStub {
@@ -44,7 +52,7 @@ impl UserStub {
     /// # async fn main() -> Result<(), wp_mini::WattpadError> {
     /// // Assume `user_stub` is a UserStub obtained from a story object.
     /// use wp_mini::types::UserStubResponse;
-    /// let user_stub = UserStubResponse { username: Some("testuser".to_string()), avatar: None, fullname: None, verified: None };
+    /// let user_stub = UserStubResponse { username: Some("testuser".to_string()), avatar: None, fullname: None, verified: None, ambassador: None, is_private: None };
     /// let client = WattpadClient::new();
     ///
     /// // Fetch the full profile for the user
@@ -66,4 +74,113 @@ impl UserStub {
             })
         }
     }
+
+    /// Fetches every story published by this user.
+    ///
+    /// This is a convenience method that uses the `username` from the stub to page through
+    /// `UserClient::get_user_stories` until the API reports no further pages.
+    ///
+    /// # Arguments
+    /// * `client` - An instance of `WattpadClient` to use for the API requests.
+    /// * `fields` - An optional slice of `StoryField` to request for each story. If `None`,
+    ///   default fields are used.
+    ///
+    /// # Returns
+    /// A `Result` containing all of the user's stories on success.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::MissingRequiredField` if the `username` field on this stub is
+    /// `None`, or the first error from the underlying API requests.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use wp_mini::WattpadClient;
+    /// # use wp_mini::field::StoryField;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    /// let story = client.story.get_story_info(12345, None).await?;
+    ///
+    /// if let Some(author) = &story.user {
+    ///     let stories = author.fetch_user_stories(&client, Some(&[StoryField::Title])).await?;
+    ///     println!("The author has published {} stories", stories.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_user_stories(
+        &self,
+        client: &WattpadClient,
+        fields: Option<&[StoryField]>,
+    ) -> Result<Vec<StoryResponse>, WattpadError> {
+        let Some(username) = &self.username else {
+            return Err(WattpadError::MissingRequiredField {
+                field: "username".to_string(),
+                context: "Cannot fetch stories without a username.".to_string(),
+            });
+        };
+
+        let mut stories = Vec::new();
+        let mut pagination = None;
+        loop {
+            let page = client
+                .user
+                .get_user_stories(username, fields, None, None, pagination)
+                .await?;
+            let current = page.offset.unwrap_or(0);
+            // Stop unless the API actually moved forward, so a bad `nextUrl` cannot loop.
+            let next = page
+                .next_offset
+                .filter(|next| !page.items.is_empty() && *next > current);
+            stories.extend(page.items);
+
+            match next {
+                Some(offset) => {
+                    pagination = Some(PaginationParams {
+                        limit: page.limit,
+                        offset: Some(offset),
+                    })
+                }
+                None => return Ok(stories),
+            }
+        }
+    }
+}
+
+impl From<UserStub> for User {
+    /// Converts a stub into an **incomplete** `UserResponse`.
+    ///
+    /// Only `username`, `avatar`, `full_name` (from the stub's `fullname`), `verified`,
+    /// `ambassador` and `is_private` are carried over. Every other field, including counts such as `num_followers`, is always
+    /// `None`; use `fetch_full_profile` to get the complete profile.
+    ///
+    /// Note that the stub's `username` is serialized as `name` by the API, while the full
+    /// user object uses `username`; the conversion maps between the two.
+    ///
+    /// # Examples
+    /// ```
+    /// use wp_mini::types::{UserResponse, UserStubResponse};
+    ///
+    /// let stub = UserStubResponse {
+    ///     username: Some("some_user".to_string()),
+    ///     fullname: Some("Some User".to_string()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let user = UserResponse::from(stub);
+    /// assert_eq!(user.username.as_deref(), Some("some_user"));
+    /// assert_eq!(user.full_name.as_deref(), Some("Some User"));
+    /// assert_eq!(user.num_followers, None);
+    /// ```
+    fn from(stub: UserStub) -> Self {
+        User {
+            username: stub.username,
+            avatar: stub.avatar,
+            full_name: stub.fullname,
+            verified: stub.verified,
+            ambassador: stub.ambassador,
+            is_private: stub.is_private,
+            ..Default::default()
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents the engagement statistics of a story, as seen by its author.
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WritingStats {
+    /// The statistics for each part of the story.
+    pub part_stats: Option<Vec<PartStats>>,
+}
+
+/// Represents the engagement statistics of a single story part.
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PartStats {
+    /// The unique identifier of the story part.
+    pub part_id: Option<u64>,
+    /// The total number of reads the part has received.
+    pub reads: Option<i64>,
+    /// The total number of votes the part has received.
+    pub votes: Option<i64>,
+    /// The total number of comments on the part.
+    pub comments: Option<i64>,
+    /// The number of reads per day, oldest first.
+    pub daily_reads: Option<Vec<i64>>,
+}
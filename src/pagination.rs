@@ -0,0 +1,201 @@
+//! Contains the types used to represent paginated results from list endpoints.
+//!
+//! With the `stream` feature enabled, it also provides `PaginationStream`, which walks
+//! through every page of a list endpoint as an asynchronous `Stream`.
+
+#[cfg(feature = "stream")]
+use crate::WattpadError;
+#[cfg(feature = "stream")]
+use futures_core::future::BoxFuture;
+#[cfg(feature = "stream")]
+use futures_core::Stream;
+#[cfg(feature = "stream")]
+use std::collections::VecDeque;
+#[cfg(feature = "stream")]
+use std::pin::Pin;
+#[cfg(feature = "stream")]
+use std::task::{Context, Poll};
+
+/// The `limit` and `offset` of a request to a paginated list endpoint.
+///
+/// Every list-returning method accepts an `Option<PaginationParams>`; passing `None` leaves
+/// both values up to the API.
+///
+/// # Examples
+/// ```
+/// use wp_mini::PaginationParams;
+///
+/// let params = PaginationParams::new(20, 40);
+/// assert_eq!((params.limit, params.offset), (Some(20), Some(40)));
+///
+/// // Pages are numbered from 1, so the third page of 20 items starts at offset 40.
+/// assert_eq!(PaginationParams::page(3, 20), PaginationParams::new(20, 40));
+/// assert_eq!(PaginationParams::page(1, 20).offset, Some(0));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct PaginationParams {
+    /// The maximum number of items to return.
+    pub limit: Option<u32>,
+    /// The number of items to skip.
+    pub offset: Option<u32>,
+}
+
+impl PaginationParams {
+    /// Creates parameters with an explicit `limit` and `offset`.
+    pub fn new(limit: u32, offset: u32) -> Self {
+        Self {
+            limit: Some(limit),
+            offset: Some(offset),
+        }
+    }
+
+    /// Creates parameters for the given page, where pages hold `page_size` items each.
+    ///
+    /// Pages are numbered from 1; page 0 is treated as the first page. The offset saturates
+    /// at `u32::MAX` rather than overflowing.
+    pub fn page(page_number: u32, page_size: u32) -> Self {
+        Self::new(page_size, page_number.saturating_sub(1).saturating_mul(page_size))
+    }
+}
+
+/// A single page of results from a paginated list endpoint.
+///
+/// Alongside the items themselves, this carries enough information to tell whether
+/// more pages exist and where the next one starts, without making an extra request.
+#[derive(Debug, Clone)]
+pub struct PaginatedResponse<T> {
+    /// The items on this page.
+    pub items: Vec<T>,
+    /// The total number of items available across all pages, if reported by the API.
+    pub total: Option<u64>,
+    /// The page size that was requested, if any.
+    pub limit: Option<u32>,
+    /// The offset of the first item on this page, if one was requested.
+    pub offset: Option<u32>,
+    /// The offset at which the next page starts, or `None` if this is the last page.
+    pub next_offset: Option<u32>,
+}
+
+impl<T> PaginatedResponse<T> {
+    /// Returns `true` if there are more pages after this one.
+    pub fn has_more(&self) -> bool {
+        self.next_offset.is_some()
+    }
+}
+
+impl<T> IntoIterator for PaginatedResponse<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+/// The future returned by the page-fetching closure of a `PaginationStream`.
+#[cfg(feature = "stream")]
+type PageFuture<'a, T> = BoxFuture<'a, Result<PaginatedResponse<T>, WattpadError>>;
+
+/// An asynchronous `Stream` over every item of a paginated list endpoint.
+///
+/// The stream buffers one page at a time. When the buffer is exhausted, the next page is
+/// fetched by calling the provided closure with the page size (`limit`) and the `offset`
+/// reported by the previous page. The stream ends after the last page, or after yielding
+/// the first error it encounters.
+///
+/// Requires the `stream` feature.
+#[cfg(feature = "stream")]
+pub struct PaginationStream<'a, T> {
+    fetch: Box<dyn FnMut(u32, u32) -> PageFuture<'a, T> + Send + 'a>,
+    page_size: u32,
+    buffer: VecDeque<T>,
+    next_offset: Option<u32>,
+    pending: Option<PageFuture<'a, T>>,
+}
+
+#[cfg(feature = "stream")]
+impl<'a, T> PaginationStream<'a, T> {
+    /// Creates a new stream that fetches pages of `page_size` items using `fetch`.
+    ///
+    /// The closure receives `(limit, offset)` and must return the corresponding page.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use futures_util::StreamExt;
+    /// # use wp_mini::{PaginationParams, PaginationStream, WattpadClient};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), wp_mini::WattpadError> {
+    /// let client = WattpadClient::new();
+    ///
+    /// let mut stories = PaginationStream::new(20, |limit, offset| {
+    ///     let page = PaginationParams::new(limit, offset);
+    ///     Box::pin(client.user.get_user_stories("test", None, None, None, Some(page)))
+    /// });
+    ///
+    /// while let Some(story) = stories.next().await {
+    ///     println!("{:?}", story?.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new<F>(page_size: u32, fetch: F) -> Self
+    where
+        F: FnMut(u32, u32) -> PageFuture<'a, T> + Send + 'a,
+    {
+        Self {
+            fetch: Box::new(fetch),
+            page_size,
+            buffer: VecDeque::new(),
+            next_offset: Some(0),
+            pending: None,
+        }
+    }
+}
+
+// The stream never hands out pinned references to its fields, so it is safe to move.
+#[cfg(feature = "stream")]
+impl<T> Unpin for PaginationStream<'_, T> {}
+
+#[cfg(feature = "stream")]
+impl<T> Stream for PaginationStream<'_, T> {
+    type Item = Result<T, WattpadError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(item) = this.buffer.pop_front() {
+                return Poll::Ready(Some(Ok(item)));
+            }
+
+            if let Some(pending) = this.pending.as_mut() {
+                let page = match pending.as_mut().poll(cx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(page) => page,
+                };
+                this.pending = None;
+
+                match page {
+                    Ok(page) => {
+                        let current = this.next_offset.take().unwrap_or(0);
+                        // Only continue if the API actually moved forward, so a misbehaving
+                        // `nextUrl` can never make the stream loop forever.
+                        this.next_offset = page
+                            .next_offset
+                            .filter(|next| !page.items.is_empty() && *next > current);
+                        this.buffer.extend(page.items);
+                    }
+                    Err(err) => {
+                        this.next_offset = None;
+                        return Poll::Ready(Some(Err(err)));
+                    }
+                }
+                continue;
+            }
+
+            match this.next_offset {
+                Some(offset) => this.pending = Some((this.fetch)(this.page_size, offset)),
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+}
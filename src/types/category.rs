@@ -0,0 +1,52 @@
+//! Named identifiers for the story categories used by Wattpad.
+
+/// Named constants for Wattpad's story category IDs.
+///
+/// These can be passed to category-aware methods such as
+/// `StoryClient::get_stories_by_category` instead of hardcoding numerical IDs.
+pub mod category_ids {
+    /// Teen Fiction.
+    pub const TEEN_FICTION: u32 = 1;
+    /// Poetry.
+    pub const POETRY: u32 = 2;
+    /// Fantasy.
+    pub const FANTASY: u32 = 3;
+    /// Romance.
+    pub const ROMANCE: u32 = 4;
+    /// Science Fiction.
+    pub const SCIENCE_FICTION: u32 = 5;
+    /// Fanfiction.
+    pub const FANFICTION: u32 = 6;
+    /// Humor.
+    pub const HUMOR: u32 = 7;
+    /// Mystery / Thriller.
+    pub const MYSTERY_THRILLER: u32 = 8;
+    /// Horror.
+    pub const HORROR: u32 = 9;
+    /// Classics.
+    pub const CLASSICS: u32 = 10;
+    /// Adventure.
+    pub const ADVENTURE: u32 = 11;
+    /// Paranormal.
+    pub const PARANORMAL: u32 = 12;
+    /// Spiritual.
+    pub const SPIRITUAL: u32 = 13;
+    /// Action.
+    pub const ACTION: u32 = 14;
+    /// Non-Fiction.
+    pub const NON_FICTION: u32 = 16;
+    /// Short Story.
+    pub const SHORT_STORY: u32 = 17;
+    /// Vampire.
+    pub const VAMPIRE: u32 = 18;
+    /// Random.
+    pub const RANDOM: u32 = 19;
+    /// General Fiction.
+    pub const GENERAL_FICTION: u32 = 21;
+    /// Werewolf.
+    pub const WEREWOLF: u32 = 22;
+    /// Historical Fiction.
+    pub const HISTORICAL_FICTION: u32 = 23;
+    /// ChickLit.
+    pub const CHICK_LIT: u32 = 24;
+}
@@ -0,0 +1,56 @@
+//! Where a new comment is posted.
+
+/// The target of a new comment, accepted by `CommentClient::post_comment`.
+///
+/// # Examples
+/// ```
+/// use wp_mini::types::CommentContext;
+///
+/// // An inline comment on the third paragraph of a part.
+/// let context = CommentContext::Paragraph { part_id: 87654321, paragraph: 2 };
+/// assert_eq!(context.part_id(), Some(87654321));
+///
+/// assert_eq!(CommentContext::Reply(42).part_id(), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CommentContext {
+    /// A comment on a story part as a whole.
+    Part(u64),
+    /// An inline comment anchored to a paragraph of a story part, by zero-based index.
+    Paragraph {
+        /// The unique identifier of the story part.
+        part_id: u64,
+        /// The zero-based index of the paragraph.
+        paragraph: u32,
+    },
+    /// A reply to the comment with the given ID.
+    Reply(u64),
+}
+
+impl CommentContext {
+    /// Returns the story part the comment is posted on, if it is not a reply.
+    pub fn part_id(&self) -> Option<u64> {
+        match self {
+            Self::Part(part_id) | Self::Paragraph { part_id, .. } => Some(*part_id),
+            Self::Reply(_) => None,
+        }
+    }
+
+    /// Returns the API path new comments in this context are posted to.
+    pub(crate) fn path(&self) -> String {
+        match self {
+            Self::Part(part_id) | Self::Paragraph { part_id, .. } => {
+                format!("/api/v3/story_parts/{}/comments", part_id)
+            }
+            Self::Reply(comment_id) => format!("/api/v3/comments/{}/replies", comment_id),
+        }
+    }
+
+    /// Returns the paragraph index for inline comments.
+    pub(crate) fn paragraph(&self) -> Option<u32> {
+        match self {
+            Self::Paragraph { paragraph, .. } => Some(*paragraph),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//! Filters for narrowing down story list results.
+
+/// Restricts which stories a list endpoint returns.
+///
+/// Every field left as `None` is not sent, so `StoryFilter::default()` applies no filtering.
+/// This is accepted by `UserClient::get_user_stories`, `StoryClient::get_trending_stories`
+/// and `StoryClient::get_stories_by_category`.
+///
+/// # Examples
+/// ```
+/// use wp_mini::types::{category_ids, StoryFilter};
+///
+/// let finished_fantasy = StoryFilter {
+///     category: Some(category_ids::FANTASY),
+///     completed: Some(true),
+///     mature: Some(false),
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(finished_fantasy.language, None);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct StoryFilter {
+    /// Only stories written in the language with this ID.
+    pub language: Option<u64>,
+    /// Only stories that are (or are not) marked as mature.
+    pub mature: Option<bool>,
+    /// Only stories that are (or are not) marked as complete.
+    pub completed: Option<bool>,
+    /// Only stories in the category with this ID. Named constants for the known categories
+    /// are available in [`crate::types::category_ids`].
+    pub category: Option<u32>,
+    /// Only stories with this content rating.
+    pub rating: Option<i64>,
+}
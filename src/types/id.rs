@@ -0,0 +1,102 @@
+//! Type-safe wrappers around the numeric IDs used by the API.
+//!
+//! Stories and parts are both identified by plain integers, which makes it easy to pass
+//! one kind of ID where the other is expected. These newtypes keep them apart while
+//! converting freely to and from `u64`.
+//!
+//! There is deliberately no user ID type: the API addresses users by username, which is
+//! covered by `Username`, and no endpoint or model exposes a numeric user ID.
+
+use crate::WattpadError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+/// Defines a `u64` ID newtype with its conversion and formatting impls.
+macro_rules! define_id {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+        #[serde(transparent)]
+        pub struct $name(pub u64);
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseIntError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                s.parse().map(Self)
+            }
+        }
+
+        impl From<u64> for $name {
+            fn from(id: u64) -> Self {
+                Self(id)
+            }
+        }
+
+        impl From<$name> for u64 {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+    };
+}
+
+define_id!(
+    /// The unique identifier of a story.
+    ///
+    /// Accepted alongside plain `u64` values by every method that takes a story ID. Those
+    /// methods return `WattpadError::InvalidInput` for a zero ID without sending a request.
+    ///
+    /// # Examples
+    /// ```
+    /// use wp_mini::types::StoryId;
+    ///
+    /// let id: StoryId = "12345".parse().unwrap();
+    /// assert_eq!(id, StoryId::from(12345));
+    /// assert_eq!(u64::from(id), 12345);
+    /// assert_eq!(id.to_string(), "12345");
+    /// assert_eq!(serde_json::to_string(&id).unwrap(), "12345");
+    /// ```
+    StoryId
+);
+
+define_id!(
+    /// The unique identifier of a story part.
+    ///
+    /// Accepted alongside plain `u64` values by every method that takes a part ID, which
+    /// likewise rejects a zero ID.
+    PartId
+);
+
+/// Rejects a zero ID before any request is made, since Wattpad never assigns it.
+fn check_non_zero(id: u64, field: &str, kind: &str) -> Result<(), WattpadError> {
+    if id == 0 {
+        return Err(WattpadError::InvalidInput {
+            field: field.to_string(),
+            reason: format!("The {} ID must not be zero.", kind),
+        });
+    }
+    Ok(())
+}
+
+/// Converts `story_id` to a `StoryId`, rejecting zero with `WattpadError::InvalidInput`.
+pub(crate) fn checked_story_id(story_id: impl Into<StoryId>) -> Result<StoryId, WattpadError> {
+    let story_id = story_id.into();
+    check_non_zero(story_id.0, "story_id", "story")?;
+    Ok(story_id)
+}
+
+/// Converts `part_id` to a `PartId`, rejecting zero with `WattpadError::InvalidInput`.
+pub(crate) fn checked_part_id(part_id: impl Into<PartId>) -> Result<PartId, WattpadError> {
+    let part_id = part_id.into();
+    check_non_zero(part_id.0, "part_id", "part")?;
+    Ok(part_id)
+}
@@ -0,0 +1,101 @@
+//! Defines the public type aliases for the data structures returned by API endpoints.
+//!
+//! These aliases provide a clear and consistent naming convention (e.g., `UserResponse`)
+//! for the types that consumers of this library will receive, distinguishing them from
+//! the internal `model` structs. This makes the library's public API more explicit.
+
+use crate::model;
+
+mod category;
+mod comment;
+mod filter;
+mod id;
+mod profile;
+mod raw;
+mod report;
+mod search;
+mod sort;
+mod username;
+
+pub use category::category_ids;
+pub use comment::CommentContext;
+pub use filter::StoryFilter;
+pub use id::{PartId, StoryId};
+pub(crate) use id::{checked_part_id, checked_story_id};
+pub use profile::UpdateProfileRequest;
+pub use raw::RawApiResponse;
+pub use report::ReportReason;
+pub use search::SearchQuery;
+pub use sort::{PartSortField, SortOrder, StorySortField};
+pub use username::Username;
+
+/// Represents the response data for a full user object. Alias for [`model::User`].
+pub type UserResponse = model::User;
+
+/// Represents the response data for a lightweight user stub. Alias for [`model::UserStub`].
+pub type UserStubResponse = model::UserStub;
+
+/// Represents the response data for a full story object. Alias for [`model::Story`].
+///
+/// All fields are optional, so test fixtures only need to spell out what they use:
+///
+/// ```
+/// use wp_mini::types::StoryResponse;
+///
+/// let story = StoryResponse {
+///     title: Some("Test".into()),
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(story.id, None);
+/// assert_eq!(story, story.clone());
+/// ```
+pub type StoryResponse = model::Story;
+
+/// Represents the response data for a lightweight story part reference. Alias for [`model::PartReference`].
+pub type PartReferenceResponse = model::PartReference;
+
+/// Represents the response data for a lightweight story part stub. Alias for [`model::PartStub`].
+pub type PartStubResponse = model::PartStub;
+
+/// Represents the response data for a full story part object. Alias for [`model::Part`].
+pub type PartResponse = model::Part;
+
+/// Represents the response data for a text URL object. Alias for [`model::TextUrl`].
+pub type TextUrlResponse = model::TextUrl;
+
+/// Represents the response data for a story part's content. Alias for [`model::PartContent`].
+pub type PartContentResponse = model::PartContent;
+
+/// Represents the response data for a comment object. Alias for [`model::Comment`].
+pub type CommentResponse = model::Comment;
+
+/// Represents the response data for a user's linked third-party accounts. Alias for [`model::ConnectedServices`].
+pub type ConnectedServicesResponse = model::ConnectedServices;
+
+/// Represents the response data for a language object. Alias for [`model::Language`].
+pub type LanguageResponse = model::Language;
+
+/// Represents the response data for a reading list object. Alias for [`model::ReadingList`].
+pub type ReadingListResponse = model::ReadingList;
+
+/// Represents the response data for a reading position. Alias for [`model::ReadingPosition`].
+pub type ReadingPositionResponse = model::ReadingPosition;
+
+/// Represents the response data for a story category object. Alias for [`model::Category`].
+pub type CategoryResponse = model::Category;
+
+/// Represents the response data for a private message object. Alias for [`model::Message`].
+pub type MessageResponse = model::Message;
+
+/// Represents the response data for a notification object. Alias for [`model::Notification`].
+pub type NotificationResponse = model::Notification;
+
+/// Represents the response data for a story announcement object. Alias for [`model::Announcement`].
+pub type AnnouncementResponse = model::Announcement;
+
+/// Represents the response data for a story's author statistics. Alias for [`model::WritingStats`].
+pub type WritingStatsResponse = model::WritingStats;
+
+/// Represents the response data for a single part's author statistics. Alias for [`model::PartStats`].
+pub type PartStatsResponse = model::PartStats;
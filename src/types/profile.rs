@@ -0,0 +1,100 @@
+//! A builder for partial updates to the authenticated user's profile.
+
+use crate::WattpadError;
+use serde::Serialize;
+
+/// The maximum number of characters Wattpad allows in a display name.
+const MAX_NAME_LENGTH: usize = 100;
+/// The maximum number of characters Wattpad allows in a profile description.
+const MAX_DESCRIPTION_LENGTH: usize = 2000;
+/// The maximum number of characters Wattpad allows in a location.
+const MAX_LOCATION_LENGTH: usize = 100;
+/// The maximum number of characters Wattpad allows in a website URL.
+const MAX_WEBSITE_LENGTH: usize = 200;
+/// The maximum number of characters Wattpad allows in a status message.
+const MAX_STATUS_LENGTH: usize = 100;
+
+/// A set of changes to the authenticated user's profile.
+///
+/// This is accepted by `UserClient::update_profile`. Only the fields that have been set are
+/// sent; everything else on the profile is left unchanged.
+///
+/// # Examples
+/// ```
+/// use wp_mini::types::UpdateProfileRequest;
+///
+/// let update = UpdateProfileRequest::new()
+///     .description("Writer of dragon stories.")
+///     .location("Toronto");
+///
+/// assert_ne!(update, UpdateProfileRequest::default());
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct UpdateProfileRequest {
+    name: Option<String>,
+    description: Option<String>,
+    location: Option<String>,
+    website: Option<String>,
+    status: Option<String>,
+}
+
+impl UpdateProfileRequest {
+    /// Creates a new, empty profile update.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the display name. At most 100 characters.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the "About Me" description. At most 2000 characters.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the self-reported location. At most 100 characters.
+    pub fn location(mut self, location: impl Into<String>) -> Self {
+        self.location = Some(location.into());
+        self
+    }
+
+    /// Sets the personal website link. At most 200 characters.
+    pub fn website(mut self, website: impl Into<String>) -> Self {
+        self.website = Some(website.into());
+        self
+    }
+
+    /// Sets the status message. At most 100 characters.
+    pub fn status(mut self, status: impl Into<String>) -> Self {
+        self.status = Some(status.into());
+        self
+    }
+
+    /// Checks every field that has been set against Wattpad's length limits.
+    pub(crate) fn validate(&self) -> Result<(), WattpadError> {
+        let fields = [
+            ("name", &self.name, MAX_NAME_LENGTH),
+            ("description", &self.description, MAX_DESCRIPTION_LENGTH),
+            ("location", &self.location, MAX_LOCATION_LENGTH),
+            ("website", &self.website, MAX_WEBSITE_LENGTH),
+            ("status", &self.status, MAX_STATUS_LENGTH),
+        ];
+
+        for (field, value, max_length) in fields {
+            if let Some(value) = value
+                && value.chars().count() > max_length
+            {
+                return Err(WattpadError::InvalidInput {
+                    field: field.to_string(),
+                    reason: format!("The {} must be at most {} characters long.", field, max_length),
+                });
+            }
+        }
+        Ok(())
+    }
+}
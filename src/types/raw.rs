@@ -0,0 +1,27 @@
+//! A typed response paired with the JSON it was parsed from.
+
+/// A response deserialized into `T`, along with the full JSON body it came from.
+///
+/// Useful for spotting fields the typed models do not cover yet, or for forwarding the
+/// original API data without re-serializing the model.
+///
+/// # Examples
+/// ```no_run
+/// # use wp_mini::WattpadClient;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), wp_mini::WattpadError> {
+/// let client = WattpadClient::new();
+/// let response = client.story.get_story_info_raw(12345678, None).await?;
+///
+/// println!("Title: {:?}", response.data.title);
+/// println!("Raw keys: {:?}", response.raw.as_object().map(|o| o.keys().collect::<Vec<_>>()));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawApiResponse<T> {
+    /// The typed response.
+    pub data: T,
+    /// The complete JSON body, including any fields `T` does not model.
+    pub raw: serde_json::Value,
+}
@@ -0,0 +1,65 @@
+//! The reasons accepted when reporting content to Wattpad's moderators.
+
+use serde::{Serialize, Serializer};
+
+/// Why a story, part or user is being reported.
+///
+/// This is accepted by `StoryClient::report_story`, `StoryClient::report_part` and
+/// `UserClient::report_user`.
+///
+/// # Examples
+/// ```
+/// use wp_mini::types::ReportReason;
+///
+/// let reason = ReportReason::Other("Impersonates another author".to_string());
+/// assert_eq!(reason.code(), "other");
+/// assert_eq!(ReportReason::HateSpeech.code(), "hate_speech");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ReportReason {
+    /// Unsolicited advertising or repetitive content.
+    Spam,
+    /// Content copied from another author without permission.
+    Plagiarism,
+    /// Content that attacks people based on a protected characteristic.
+    HateSpeech,
+    /// Content that breaks Wattpad's content guidelines in some other way.
+    Inappropriate,
+    /// Any other reason, described in free text.
+    Other(String),
+}
+
+impl ReportReason {
+    /// Returns the reason code sent to the API.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Spam => "spam",
+            Self::Plagiarism => "plagiarism",
+            Self::HateSpeech => "hate_speech",
+            Self::Inappropriate => "inappropriate",
+            Self::Other(_) => "other",
+        }
+    }
+}
+
+/// Serializes as the JSON report payload, e.g. `{"reason":"other","details":"..."}`.
+impl Serialize for ReportReason {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[serde_with::skip_serializing_none]
+        #[derive(Serialize)]
+        struct Payload<'a> {
+            reason: &'static str,
+            details: Option<&'a str>,
+        }
+
+        let details = match self {
+            Self::Other(details) => Some(details.as_str()),
+            _ => None,
+        };
+        Payload {
+            reason: self.code(),
+            details,
+        }
+        .serialize(serializer)
+    }
+}
@@ -0,0 +1,125 @@
+//! A builder for composing structured story searches.
+
+use super::{SortOrder, StorySortField};
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+
+/// A structured story search, composed from a keyword and optional filters.
+///
+/// This is accepted by `SearchClient::search_stories_with` as a type-safe alternative
+/// to passing a raw query string.
+///
+/// # Examples
+/// ```
+/// use wp_mini::types::{category_ids, SearchQuery};
+///
+/// let query = SearchQuery::new()
+///     .keyword("dragon riders")
+///     .category(category_ids::FANTASY)
+///     .completed(true);
+///
+/// assert_eq!(query.to_query_string(), "query=dragon%20riders&completed=true&category=3");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchQuery {
+    keyword: Option<String>,
+    language: Option<u64>,
+    mature: Option<bool>,
+    completed: Option<bool>,
+    category: Option<u32>,
+    sort_by: Option<(StorySortField, SortOrder)>,
+}
+
+impl SearchQuery {
+    /// Creates a new, empty search query.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the search terms to match against story titles, descriptions, and tags.
+    pub fn keyword(mut self, keyword: impl Into<String>) -> Self {
+        self.keyword = Some(keyword.into());
+        self
+    }
+
+    /// Restricts the results to stories written in the language with the given ID.
+    pub fn language(mut self, id: u64) -> Self {
+        self.language = Some(id);
+        self
+    }
+
+    /// Restricts the results to stories that are (or are not) marked as mature.
+    pub fn mature(mut self, mature: bool) -> Self {
+        self.mature = Some(mature);
+        self
+    }
+
+    /// Restricts the results to stories that are (or are not) marked as complete.
+    pub fn completed(mut self, completed: bool) -> Self {
+        self.completed = Some(completed);
+        self
+    }
+
+    /// Restricts the results to stories in the category with the given ID.
+    ///
+    /// Named constants for the known categories are available in [`crate::types::category_ids`].
+    pub fn category(mut self, id: u32) -> Self {
+        self.category = Some(id);
+        self
+    }
+
+    /// Orders the results by `field` in the given direction instead of by relevance.
+    ///
+    /// # Examples
+    /// ```
+    /// use wp_mini::types::{SearchQuery, SortOrder, StorySortField};
+    ///
+    /// let query = SearchQuery::new()
+    ///     .keyword("vampires")
+    ///     .sort_by(StorySortField::VoteCount, SortOrder::Descending);
+    ///
+    /// assert_eq!(query.to_query_string(), "query=vampires&sort=voteCount&order=desc");
+    /// ```
+    pub fn sort_by(mut self, field: StorySortField, order: SortOrder) -> Self {
+        self.sort_by = Some((field, order));
+        self
+    }
+
+    /// Returns the search terms, if any were set.
+    pub(crate) fn keyword_str(&self) -> Option<&str> {
+        self.keyword.as_deref()
+    }
+
+    /// Returns the query parameters for every component that has been set.
+    pub(crate) fn query_pairs(&self) -> Vec<(&'static str, String)> {
+        let mut pairs = Vec::new();
+        if let Some(keyword) = &self.keyword {
+            pairs.push(("query", keyword.clone()));
+        }
+        if let Some(language) = self.language {
+            pairs.push(("language", language.to_string()));
+        }
+        if let Some(mature) = self.mature {
+            pairs.push(("mature", mature.to_string()));
+        }
+        if let Some(completed) = self.completed {
+            pairs.push(("completed", completed.to_string()));
+        }
+        if let Some(category) = self.category {
+            pairs.push(("category", category.to_string()));
+        }
+        if let Some((field, order)) = self.sort_by {
+            pairs.push(("sort", field.code().to_string()));
+            pairs.push(("order", order.code().to_string()));
+        }
+        pairs
+    }
+
+    /// Produces the URL-encoded query string for this search.
+    pub fn to_query_string(&self) -> String {
+        self.query_pairs()
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, utf8_percent_encode(value, NON_ALPHANUMERIC)))
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+}
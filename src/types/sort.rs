@@ -0,0 +1,91 @@
+//! The orderings accepted by story and part list endpoints.
+
+/// The direction in which results are sorted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SortOrder {
+    /// Smallest or oldest first.
+    Ascending,
+    /// Largest or newest first.
+    Descending,
+}
+
+impl SortOrder {
+    /// Returns the value sent in the `order` query parameter.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Ascending => "asc",
+            Self::Descending => "desc",
+        }
+    }
+}
+
+/// The story attribute that results are sorted by.
+///
+/// This is accepted, together with a `SortOrder`, by `UserClient::get_user_stories` and
+/// `SearchClient::search_stories`, and by `SearchQuery::sort_by`.
+///
+/// # Examples
+/// ```
+/// use wp_mini::types::{SortOrder, StorySortField};
+///
+/// let sort_by = (StorySortField::ReadCount, SortOrder::Descending);
+/// assert_eq!(sort_by.0.code(), "readCount");
+/// assert_eq!(sort_by.1.code(), "desc");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StorySortField {
+    /// The total number of votes.
+    VoteCount,
+    /// The total number of reads.
+    ReadCount,
+    /// When the story was created.
+    CreateDate,
+    /// When the story was last modified.
+    ModifyDate,
+}
+
+impl StorySortField {
+    /// Returns the value sent in the `sort` query parameter.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::VoteCount => "voteCount",
+            Self::ReadCount => "readCount",
+            Self::CreateDate => "createDate",
+            Self::ModifyDate => "modifyDate",
+        }
+    }
+}
+
+/// The part attribute that a story's parts are sorted by.
+///
+/// This is accepted, together with a `SortOrder`, by `StoryClient::get_story_parts_sorted`.
+///
+/// # Examples
+/// ```
+/// use wp_mini::types::PartSortField;
+///
+/// assert_eq!(PartSortField::VoteCount.code(), "voteCount");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PartSortField {
+    /// When the part was created.
+    CreateDate,
+    /// When the part was last modified.
+    ModifyDate,
+    /// The total number of votes.
+    VoteCount,
+    /// The total number of reads.
+    ReadCount,
+}
+
+impl PartSortField {
+    /// Returns the value sent in the `sort` query parameter.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::CreateDate => "createDate",
+            Self::ModifyDate => "modifyDate",
+            Self::VoteCount => "voteCount",
+            Self::ReadCount => "readCount",
+        }
+    }
+}
@@ -0,0 +1,108 @@
+//! A validated Wattpad username.
+
+use crate::WattpadError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// The shortest username Wattpad allows.
+const MIN_LENGTH: usize = 3;
+/// The longest username Wattpad allows.
+const MAX_LENGTH: usize = 30;
+
+/// A username that follows Wattpad's format rules: 3 to 30 ASCII letters, digits or
+/// underscores.
+///
+/// Accepted by every `UserClient` method that puts a username in the request path. Those
+/// methods also take a plain `&str`, `String` or `&String` and validate it the same way
+/// before making any request.
+///
+/// # Examples
+/// ```
+/// use wp_mini::types::Username;
+/// use wp_mini::WattpadError;
+///
+/// let username = Username::new("some_user").unwrap();
+/// assert_eq!(username.as_ref(), "some_user");
+///
+/// assert!(matches!(Username::new("no"), Err(WattpadError::InvalidInput { .. })));
+/// assert!("has spaces".parse::<Username>().is_err());
+///
+/// let owned = String::from("some_user");
+/// assert_eq!(Username::try_from(&owned).unwrap(), username);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String")]
+pub struct Username(String);
+
+impl Username {
+    /// Validates `s` and wraps it as a `Username`.
+    ///
+    /// # Errors
+    /// Returns `WattpadError::InvalidInput` if `s` is not 3 to 30 characters long or
+    /// contains anything other than ASCII letters, digits and underscores.
+    pub fn new(s: &str) -> Result<Self, WattpadError> {
+        let invalid = |reason: String| WattpadError::InvalidInput {
+            field: "username".to_string(),
+            reason,
+        };
+
+        if !(MIN_LENGTH..=MAX_LENGTH).contains(&s.len()) {
+            return Err(invalid(format!(
+                "Must be between {} and {} characters long.",
+                MIN_LENGTH, MAX_LENGTH
+            )));
+        }
+        if let Some(c) = s.chars().find(|c| !c.is_ascii_alphanumeric() && *c != '_') {
+            return Err(invalid(format!(
+                "Must contain only letters, digits and underscores, found {:?}.",
+                c
+            )));
+        }
+        Ok(Self(s.to_string()))
+    }
+}
+
+impl fmt::Display for Username {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for Username {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for Username {
+    type Err = WattpadError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl TryFrom<&str> for Username {
+    type Error = WattpadError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::new(s)
+    }
+}
+
+impl TryFrom<&String> for Username {
+    type Error = WattpadError;
+
+    fn try_from(s: &String) -> Result<Self, Self::Error> {
+        Self::new(s)
+    }
+}
+
+impl TryFrom<String> for Username {
+    type Error = WattpadError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::new(&s)
+    }
+}